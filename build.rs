@@ -0,0 +1,4 @@
+// 마이그레이션 파일 변경 시 재빌드 (sqlx::migrate! 매크로가 컴파일 시점에 파일을 포함)
+fn main() {
+    println!("cargo:rerun-if-changed=migrations");
+}
@@ -0,0 +1,50 @@
+-- 초기 스키마
+-- 기존 부팅 스크립트(`01-create-schema.sql`)와 같은 스키마 (테스트 데이터 제외)
+-- 부팅 스크립트로 생성된 데이터베이스에도 적용할 수 있도록 IF NOT EXISTS 사용하며, 이후 추가된 컬럼과 테이블은 다음 마이그레이션에서 추가
+
+-- 시퀀스 생성
+CREATE SEQUENCE IF NOT EXISTS items_id_seq;
+CREATE SEQUENCE IF NOT EXISTS bids_id_seq;
+CREATE SEQUENCE IF NOT EXISTS events_id_seq;
+
+-- Items 테이블 생성
+CREATE TABLE IF NOT EXISTS items (
+   id BIGINT PRIMARY KEY DEFAULT nextval('items_id_seq'),
+   title TEXT NOT NULL,
+   description TEXT,
+   starting_price BIGINT NOT NULL,
+   current_price BIGINT NOT NULL,
+   buy_now_price BIGINT NOT NULL,
+   start_time TIMESTAMP WITH TIME ZONE NOT NULL,
+   end_time TIMESTAMP WITH TIME ZONE NOT NULL,
+   seller TEXT NOT NULL,
+   status TEXT NOT NULL,
+   created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Bids 테이블 생성
+CREATE TABLE IF NOT EXISTS bids (
+   id BIGINT PRIMARY KEY DEFAULT nextval('bids_id_seq'),
+   item_id BIGINT NOT NULL,
+   bid_time TIMESTAMP WITH TIME ZONE NOT NULL,
+   bidder_id BIGINT NOT NULL,
+   bid_amount BIGINT NOT NULL,
+   FOREIGN KEY (item_id) REFERENCES items(id)
+);
+
+-- Events 테이블 생성
+CREATE TABLE IF NOT EXISTS events (
+   id BIGINT PRIMARY KEY DEFAULT nextval('events_id_seq'),
+   aggregate_id BIGINT NOT NULL,
+   event_type TEXT NOT NULL,
+   data JSONB NOT NULL,
+   version BIGINT NOT NULL,
+   timestamp TIMESTAMP WITH TIME ZONE NOT NULL,
+   UNIQUE (aggregate_id, version)
+);
+
+-- 인덱스 생성
+CREATE INDEX IF NOT EXISTS idx_bids_item_id ON bids(item_id);
+CREATE INDEX IF NOT EXISTS idx_bids_bid_time ON bids(bid_time DESC);
+CREATE INDEX IF NOT EXISTS idx_events_aggregate_id_version ON events(aggregate_id, version);
+CREATE INDEX IF NOT EXISTS idx_events_timestamp ON events(timestamp);
@@ -0,0 +1,10 @@
+-- 입찰 규칙
+-- 최저 낙찰가, 상품별 입찰 단위, 마감 연장, 낙찰자, 자동 입찰 여부
+
+ALTER TABLE items ADD COLUMN IF NOT EXISTS reserve_price BIGINT; -- 판매자가 지정한 최저 낙찰가 (NULL: 없음)
+ALTER TABLE items ADD COLUMN IF NOT EXISTS bid_increments JSONB; -- 상품별 입찰 단위 [{"below": 10000, "increment": 500}, ...] (NULL: 전역 설정)
+ALTER TABLE items ADD COLUMN IF NOT EXISTS soft_close_window_secs BIGINT NOT NULL DEFAULT 0; -- 종료 전 이 시간(초) 내 입찰 시 종료 시간 연장 (0: 사용 안 함)
+ALTER TABLE items ADD COLUMN IF NOT EXISTS soft_close_extension_secs BIGINT NOT NULL DEFAULT 0; -- 연장 시간(초)
+ALTER TABLE items ADD COLUMN IF NOT EXISTS winner_id BIGINT; -- 낙찰자 (NULL: 미결정 또는 유찰)
+
+ALTER TABLE bids ADD COLUMN IF NOT EXISTS is_auto BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,50 @@
+-- 이벤트 처리
+-- 애그리거트 스냅샷, 아웃박스, 조회 모델 반영 위치, 데드레터
+
+-- Snapshots 테이블 생성 (애그리거트 상태 스냅샷)
+CREATE TABLE IF NOT EXISTS snapshots (
+   aggregate_id BIGINT NOT NULL,
+   version BIGINT NOT NULL,
+   schema_version INT NOT NULL, -- 애그리거트 구조 버전 (변경 시 이전 스냅샷 무시)
+   data JSONB NOT NULL,
+   created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+   PRIMARY KEY (aggregate_id, version)
+);
+
+-- Outbox 테이블 생성 (이벤트와 같은 트랜잭션으로 기록, 릴레이가 Kafka로 발행)
+CREATE TABLE IF NOT EXISTS outbox (
+   id BIGSERIAL PRIMARY KEY,
+   event_id BIGINT NOT NULL REFERENCES events(id),
+   topic TEXT NOT NULL,
+   message_key TEXT NOT NULL,
+   payload JSONB NOT NULL,
+   attempts INT NOT NULL DEFAULT 0,
+   last_error TEXT,
+   next_attempt_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+   created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+   sent_at TIMESTAMP WITH TIME ZONE -- 발행 완료 시각 (NULL이면 발행 대기)
+);
+
+-- Projection Offsets 테이블 생성 (애그리거트별 조회 모델에 마지막으로 반영한 이벤트)
+CREATE TABLE IF NOT EXISTS projection_offsets (
+   aggregate_id BIGINT PRIMARY KEY,
+   last_event_id BIGINT NOT NULL,
+   last_version BIGINT NOT NULL,
+   updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Dead Letters 테이블 생성 (조회 모델에 반영하지 못해 데드레터 토픽으로 보낸 메시지)
+CREATE TABLE IF NOT EXISTS dead_letters (
+   id BIGSERIAL PRIMARY KEY,
+   topic TEXT NOT NULL,
+   partition_id INT NOT NULL,
+   message_offset BIGINT NOT NULL,
+   message_key TEXT,
+   payload TEXT NOT NULL,
+   error TEXT NOT NULL,
+   retry_count INT NOT NULL,
+   created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+   replayed_at TIMESTAMP WITH TIME ZONE -- 재처리 완료 시각 (NULL이면 재처리 대기)
+);
+
+CREATE INDEX IF NOT EXISTS idx_outbox_pending ON outbox(next_attempt_at, id) WHERE sent_at IS NULL;
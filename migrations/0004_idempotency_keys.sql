@@ -0,0 +1,19 @@
+-- 멱등성 키
+-- 멱등성 키별 요청 및 응답을 저장하고, 이벤트에 이를 발생시킨 명령의 키를 기록
+
+ALTER TABLE events ADD COLUMN IF NOT EXISTS idempotency_key TEXT; -- 이벤트를 발생시킨 명령의 멱등성 키
+
+-- Idempotency Keys 테이블 생성 (멱등성 키별 요청 및 응답 저장)
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+   idempotency_key TEXT PRIMARY KEY,
+   endpoint TEXT NOT NULL,
+   request JSONB NOT NULL,
+   status_code INT, -- NULL이면 처리 중
+   content_type TEXT,
+   response_body TEXT,
+   created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+   expires_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_events_idempotency_key ON events(idempotency_key) WHERE idempotency_key IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_idempotency_keys_expires_at ON idempotency_keys(expires_at);
@@ -0,0 +1,7 @@
+-- 입찰 철회
+-- 조회 모델의 입찰을 이벤트 버전으로 찾을 수 있도록 기록하고, 철회된 입찰은 삭제하지 않고 철회 시각을 기록
+
+ALTER TABLE bids ADD COLUMN IF NOT EXISTS event_version BIGINT; -- 입찰 이벤트 버전 (NULL: 도입 전 입찰)
+ALTER TABLE bids ADD COLUMN IF NOT EXISTS retracted_at TIMESTAMP WITH TIME ZONE; -- 철회 시각 (NULL: 유효한 입찰)
+
+CREATE INDEX IF NOT EXISTS idx_bids_item_id_event_version ON bids(item_id, event_version);
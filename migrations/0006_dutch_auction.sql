@@ -0,0 +1,6 @@
+-- 네덜란드식(가격 하락식) 경매
+-- 기존 상품은 오름차순 경매(ENGLISH)로 유지
+
+ALTER TABLE items ADD COLUMN IF NOT EXISTS auction_type TEXT NOT NULL DEFAULT 'ENGLISH'; -- 경매 방식 (ENGLISH: 오름차순, DUTCH: 가격 하락식)
+ALTER TABLE items ADD COLUMN IF NOT EXISTS price_drop_amount BIGINT NOT NULL DEFAULT 0; -- 가격 하락 금액 (DUTCH)
+ALTER TABLE items ADD COLUMN IF NOT EXISTS price_drop_interval_secs BIGINT NOT NULL DEFAULT 0; -- 가격 하락 주기(초) (DUTCH)
@@ -0,0 +1,4 @@
+-- 멱등성 키 선점 임대
+-- 처리 중 프로세스가 중단되어 응답이 저장되지 않은 키는 선점 시각이 임대 시간을 지나면 새 요청이 다시 선점
+
+ALTER TABLE idempotency_keys ADD COLUMN IF NOT EXISTS locked_at TIMESTAMP WITH TIME ZONE; -- 선점 시각 (NULL: 처리 완료)
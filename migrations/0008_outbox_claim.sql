@@ -0,0 +1,7 @@
+-- 아웃박스 메시지 선점
+-- 릴레이는 발행할 메시지를 선점 후 트랜잭션을 끝내고 발행하며, 선점 시간이 지나도록 발행 완료되지 않은 메시지는 다시 선점
+-- 같은 애그리거트의 메시지는 가장 오래된 발행 대기 메시지가 발행 가능할 때만 선점하여 순서 유지
+
+ALTER TABLE outbox ADD COLUMN IF NOT EXISTS claimed_until TIMESTAMP WITH TIME ZONE; -- 선점 만료 시각 (NULL: 선점되지 않음)
+
+CREATE INDEX IF NOT EXISTS idx_outbox_pending_key ON outbox(message_key, id) WHERE sent_at IS NULL;
@@ -0,0 +1,13 @@
+-- 이벤트 위치
+-- 저장 시 시퀀스로 위치(position)를 발급하며, SSE 재연결 시 이어받기 기준으로 사용 (기존 이벤트는 id를 위치로 사용)
+-- 위치는 저장(INSERT) 시점에 발급되므로 커밋 순서와 다를 수 있음 (커밋 순서에 맞는 조회는 애플리케이션에서 처리)
+
+CREATE SEQUENCE IF NOT EXISTS events_position_seq;
+
+ALTER TABLE events ADD COLUMN IF NOT EXISTS position BIGINT; -- 이벤트 위치 (저장 시 발급)
+UPDATE events SET position = id WHERE position IS NULL;
+SELECT setval('events_position_seq', (SELECT COALESCE(MAX(position), 0) + 1 FROM events), false);
+ALTER TABLE events ALTER COLUMN position SET DEFAULT nextval('events_position_seq');
+ALTER TABLE events ALTER COLUMN position SET NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_events_position ON events(position);
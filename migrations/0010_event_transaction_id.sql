@@ -0,0 +1,7 @@
+-- 이벤트를 저장한 트랜잭션 id
+-- 위치(position)는 저장 시 발급되어 먼저 발급된 위치가 나중에 커밋될 수 있으므로, 조회 시 진행 중인 트랜잭션보다 먼저 시작한 트랜잭션의 이벤트만 (트랜잭션 id, 위치) 순으로 반환
+-- 기존 이벤트는 마이그레이션 트랜잭션 id로 기록되어 이후 이벤트보다 앞서며, 서로는 위치 순
+
+ALTER TABLE events ADD COLUMN IF NOT EXISTS transaction_id xid8 NOT NULL DEFAULT pg_current_xact_id();
+
+CREATE INDEX IF NOT EXISTS idx_events_transaction_id_position ON events(transaction_id, position);
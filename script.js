@@ -3,6 +3,7 @@ let ITEM_ID = null;
 
 // API 엔드포인트
 const API_URL = "http://localhost:3000";
+const WS_URL = "ws://localhost:3000";
 
 // DOM 요소들
 const itemListEl = document.getElementById("itemList");
@@ -106,6 +107,11 @@ async function showItemDetails(itemId) {
       itemsList[index] = item;
     }
 
+    // 다른 아이템을 선택하면 실시간 구독 변경
+    if (ITEM_ID !== item.id) {
+      subscribeAuction(item.id);
+    }
+
     // ITEM_ID를 선택한 아이템의 ID로 업데이트
     ITEM_ID = item.id;
 
@@ -126,6 +132,43 @@ async function showItemDetails(itemId) {
   }
 }
 
+// 실시간 구독 (WebSocket)
+let auctionSocket = null;
+let lastVersion = null;
+
+// 선택한 아이템 구독 시작
+function subscribeAuction(itemId) {
+  if (auctionSocket) {
+    auctionSocket.onclose = null;
+    auctionSocket.close();
+  }
+  lastVersion = null;
+  connectAuctionSocket(itemId);
+}
+
+// 구독 연결 (재연결 시 마지막으로 받은 버전 이후 이벤트부터 수신)
+function connectAuctionSocket(itemId) {
+  const query = lastVersion === null ? "" : `?last_version=${lastVersion}`;
+  const socket = new WebSocket(`${WS_URL}/ws/auction/${itemId}${query}`);
+
+  socket.onmessage = (message) => {
+    const data = JSON.parse(message.data);
+    lastVersion = data.version;
+    // 가격 변경, 입찰, 연장, 종료 이벤트를 받으면 상세 정보 갱신
+    if (data.type === "event" && ITEM_ID === itemId) {
+      showItemDetails(itemId);
+    }
+  };
+
+  socket.onclose = () => {
+    if (ITEM_ID === itemId) {
+      setTimeout(() => connectAuctionSocket(itemId), 1000);
+    }
+  };
+
+  auctionSocket = socket;
+}
+
 // UI 업데이트 함수
 function updateItemDetailsUI(item, bids) {
   if (itemIdEl) itemIdEl.textContent = item.id;
@@ -282,8 +325,8 @@ async function placeBid() {
     const data = await response.json();
 
     if (response.ok) {
+      // 상세 정보는 실시간 구독으로 갱신
       alert(data.message);
-      showItemDetails(ITEM_ID);
       console.log("입찰 성공");
     } else {
       handleBidError(data);
@@ -309,7 +352,7 @@ function handleBidError(data) {
       alert("경매가 이미 종료되었습니다. 다른 경매에 참여해주세요.");
       break;
     default:
-      alert(`입찰 실패: ${data.error}`);
+      alert(`입찰 실패: ${data.detail}`);
   }
 }
 
@@ -360,12 +403,11 @@ async function buyNow() {
 
     if (response.ok) {
       alert("즉시 구매 성공!");
-      showItemDetails(ITEM_ID);
     } else {
       const errorData = await response.json();
       alert(
         `즉시 구매 실패: ${
-          errorData.error || "알 수 없는 오류가 발생했습니다."
+          errorData.detail || "알 수 없는 오류가 발생했습니다."
         }`
       );
     }
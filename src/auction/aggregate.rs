@@ -0,0 +1,682 @@
+/// 경매 애그리거트
+/// 상품 등록 정보를 초기 상태로 하고, 이벤트 저장소의 이벤트를 버전 순으로 적용하여 현재 경매 상태를 재구성
+/// 상품 등록 명령으로 등록한 상품은 첫 이벤트(`ItemListed`)부터, 그 외 상품은 조회 모델의 상품 정보부터 시작
+/// 조회 모델에서 시작하는 상품은 상태 변경 이벤트가 없으면 조회 모델의 상태를, 입찰은 이벤트 도입 전 입찰을 초기 상태로 사용
+/// 명령(입찰, 즉시 구매, 경매 종료)은 비동기로 갱신되는 조회 모델 대신 이 상태를 기준으로 검증
+/// 이벤트가 많은 상품은 일정 버전마다 스냅샷을 저장하고, 최신 스냅샷 이후의 이벤트만 적용
+// region:    --- Imports
+use crate::auction::events::AuctionEvent;
+use crate::bidding::increment::{self, IncrementStep};
+use crate::bidding::model::{default_auction_type, Bid, Item, ProxyBid};
+use crate::error::AuctionError;
+use crate::event_store::{Event, EventStore, Snapshot};
+use crate::query::repository::ItemRepository;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use tracing::{info, warn};
+
+// endregion: --- Imports
+
+// region:    --- Auction Aggregate
+/// 스냅샷 구조 버전
+/// 애그리거트 구조나 이벤트 적용 로직이 바뀌면 값을 올려 이전 스냅샷을 무효화
+pub const SNAPSHOT_SCHEMA_VERSION: i32 = 5;
+
+/// 최저 낙찰가가 없는 네덜란드식 경매의 가격 하한
+pub const DUTCH_FLOOR_PRICE: i64 = 1;
+
+/// 상태를 변경하는 이벤트 (조회 모델에서 시작하는 상품은 이 이벤트가 없으면 조회 모델의 상태 사용)
+const LIFECYCLE_EVENT_TYPES: [&str; 5] = [
+    "AuctionStarted",
+    "AuctionClosed",
+    "BuyNowExecuted",
+    "ItemWithdrawn",
+    "AuctionCancelled",
+];
+
+/// 기본 스냅샷 주기 (버전 수)
+const DEFAULT_SNAPSHOT_INTERVAL: i64 = 100;
+
+static SNAPSHOT_INTERVAL: OnceLock<i64> = OnceLock::new();
+
+/// 스냅샷 주기 조회 (`SNAPSHOT_INTERVAL` 환경 변수)
+pub fn snapshot_interval() -> i64 {
+    *SNAPSHOT_INTERVAL.get_or_init(|| {
+        std::env::var("SNAPSHOT_INTERVAL")
+            .ok()
+            .and_then(|value| value.parse::<i64>().ok())
+            .filter(|interval| *interval > 0)
+            .unwrap_or(DEFAULT_SNAPSHOT_INTERVAL)
+    })
+}
+
+/// 애그리거트에 반영된 입찰
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateBid {
+    pub version: i64,
+    pub bidder_id: i64,
+    pub bid_amount: i64,
+    pub is_auto: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// 상품 등록 정보 (상품 등록 및 수정 명령에서 검증하는 항목)
+#[derive(Debug, Clone)]
+pub struct ListingFields {
+    pub title: String,
+    pub description: String,
+    pub starting_price: i64,
+    pub buy_now_price: i64,
+    pub reserve_price: Option<i64>,
+    pub bid_increments: Option<Vec<IncrementStep>>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub soft_close_window_secs: i64,
+    pub soft_close_extension_secs: i64,
+    pub auction_type: String,
+    pub price_drop_amount: i64,
+    pub price_drop_interval_secs: i64,
+}
+
+/// 경매 애그리거트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuctionAggregate {
+    pub item_id: i64,
+    pub version: i64,
+    pub status: String,
+    pub title: String,
+    pub description: String,
+    pub seller: String,
+    pub starting_price: i64,
+    pub current_price: i64,
+    pub buy_now_price: i64,
+    pub reserve_price: Option<i64>,
+    pub bid_increments: Option<Vec<IncrementStep>>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub soft_close_window_secs: i64,
+    pub soft_close_extension_secs: i64,
+    pub auction_type: String,
+    pub price_drop_amount: i64,
+    pub price_drop_interval_secs: i64,
+    pub bids: Vec<AggregateBid>,
+    pub proxy_bids: Vec<ProxyBid>,
+    pub winner_id: Option<i64>,
+}
+
+impl AuctionAggregate {
+    /// 상품 정보로 초기 상태 생성
+    /// 가격은 시작 가격에서 출발하여 입찰 적용으로 재계산
+    pub fn from_item(item: &Item) -> Self {
+        Self {
+            item_id: item.id,
+            version: 0,
+            status: item.status.clone(),
+            title: item.title.clone(),
+            description: item.description.clone(),
+            seller: item.seller.clone(),
+            starting_price: item.starting_price,
+            current_price: item.starting_price,
+            buy_now_price: item.buy_now_price,
+            reserve_price: item.reserve_price,
+            bid_increments: item.bid_increments.as_ref().map(|ladder| ladder.0.clone()),
+            start_time: item.start_time,
+            end_time: item.end_time,
+            soft_close_window_secs: item.soft_close_window_secs,
+            soft_close_extension_secs: item.soft_close_extension_secs,
+            auction_type: item.auction_type.clone(),
+            price_drop_amount: item.price_drop_amount,
+            price_drop_interval_secs: item.price_drop_interval_secs,
+            bids: Vec::new(),
+            proxy_bids: Vec::new(),
+            winner_id: None,
+        }
+    }
+
+    /// 상품 등록 이벤트 적용 전 빈 상태 생성
+    pub(crate) fn unlisted(item_id: i64) -> Self {
+        Self {
+            item_id,
+            version: 0,
+            status: "SCHEDULED".to_string(),
+            title: String::new(),
+            description: String::new(),
+            seller: String::new(),
+            starting_price: 0,
+            current_price: 0,
+            buy_now_price: 0,
+            reserve_price: None,
+            bid_increments: None,
+            start_time: DateTime::<Utc>::MIN_UTC,
+            end_time: DateTime::<Utc>::MIN_UTC,
+            soft_close_window_secs: 0,
+            soft_close_extension_secs: 0,
+            auction_type: default_auction_type(),
+            price_drop_amount: 0,
+            price_drop_interval_secs: 0,
+            bids: Vec::new(),
+            proxy_bids: Vec::new(),
+            winner_id: None,
+        }
+    }
+
+    /// 이벤트 적용 전 초기 상태
+    /// 첫 이벤트가 상품 등록 이벤트면 빈 상태, 아니면 조회 모델의 상품 정보 사용
+    /// 1. 상태: 상태 변경 이벤트가 없으면 조회 모델의 상태, 있으면 시작 전(`SCHEDULED`)에서 이벤트로 재구성
+    /// 2. 입찰 및 현재 가격: 이벤트 도입 전 입찰 (이벤트로 기록된 입찰은 이벤트 적용으로 반영)
+    /// 3. 종료 시간: 조회 모델에는 연장이 반영되어 있을 수 있으므로, 연장 이벤트가 있으면 첫 연장 전 종료 시간
+    async fn initial(
+        items: &impl ItemRepository,
+        item_id: i64,
+        events: &[Event],
+    ) -> Result<Self, AuctionError> {
+        match events.first() {
+            Some(event) if event.version == 1 && event.event_type == "ItemListed" => {
+                Ok(Self::unlisted(item_id))
+            }
+            _ => {
+                let mut aggregate = Self::from_item(&items.get_item(item_id).await?);
+                if events
+                    .iter()
+                    .any(|event| LIFECYCLE_EVENT_TYPES.contains(&event.event_type.as_str()))
+                {
+                    aggregate.status = "SCHEDULED".to_string();
+                }
+                aggregate.seed_legacy_bids(items.get_legacy_bids(item_id).await?);
+                let original_end_time = events
+                    .iter()
+                    .filter(|event| event.event_type == "AuctionExtended")
+                    .find_map(|event| match serde_json::from_value(event.data.clone()) {
+                        Ok(AuctionEvent::AuctionExtended {
+                            previous_end_time, ..
+                        }) => Some(previous_end_time),
+                        _ => None,
+                    });
+                if let Some(end_time) = original_end_time {
+                    aggregate.end_time = end_time;
+                }
+                Ok(aggregate)
+            }
+        }
+    }
+
+    /// 이벤트 도입 전 입찰 반영
+    /// 이벤트 버전이 없으므로 입찰 순서대로 0 이하의 버전을 부여하며, 철회된 입찰은 철회 이벤트 적용으로 제외
+    fn seed_legacy_bids(&mut self, bids: Vec<Bid>) {
+        let first_version = 1 - bids.len() as i64;
+        for (index, bid) in bids.into_iter().enumerate() {
+            self.current_price = self.current_price.max(bid.bid_amount);
+            self.bids.push(AggregateBid {
+                version: first_version + index as i64,
+                bidder_id: bid.bidder_id,
+                bid_amount: bid.bid_amount,
+                is_auto: bid.is_auto,
+                timestamp: bid.bid_time,
+            });
+        }
+    }
+
+    /// 이벤트 저장소로부터 애그리거트 재구성
+    /// 최신 스냅샷이 있으면 스냅샷 이후의 이벤트만 적용하며, 스냅샷 주기 이상 적용했으면 새 스냅샷 저장
+    pub async fn load(
+        event_store: &impl EventStore,
+        items: &impl ItemRepository,
+        item_id: i64,
+    ) -> Result<Self, AuctionError> {
+        let snapshot = event_store
+            .load_snapshot(item_id, SNAPSHOT_SCHEMA_VERSION)
+            .await?
+            .and_then(
+                |snapshot| match serde_json::from_value::<Self>(snapshot.data) {
+                    Ok(aggregate) => Some(aggregate),
+                    Err(e) => {
+                        warn!(
+                            "{:<12} --> 스냅샷 복원 실패, 전체 이벤트 재적용: item_id={}, {}",
+                            "Aggregate", item_id, e
+                        );
+                        None
+                    }
+                },
+            );
+
+        let (mut aggregate, events) = match snapshot {
+            Some(aggregate) => {
+                let events = event_store.load_events(item_id, aggregate.version).await?;
+                (aggregate, events)
+            }
+            None => {
+                let events = event_store.load_events(item_id, 0).await?;
+                (Self::initial(items, item_id, &events).await?, events)
+            }
+        };
+        let snapshot_version = aggregate.version;
+
+        for event in &events {
+            aggregate.apply(event)?;
+        }
+
+        // 스냅샷 정책: 마지막 스냅샷 이후 스냅샷 주기 이상 이벤트가 쌓이면 저장 (실패해도 명령은 계속 처리)
+        if aggregate.version - snapshot_version >= snapshot_interval() {
+            if let Err(e) = aggregate.save_snapshot(event_store).await {
+                warn!(
+                    "{:<12} --> 스냅샷 저장 실패: item_id={}, {}",
+                    "Aggregate", item_id, e
+                );
+            }
+        }
+        Ok(aggregate)
+    }
+
+    /// 현재 상태를 스냅샷으로 저장
+    pub async fn save_snapshot(&self, event_store: &impl EventStore) -> Result<(), AuctionError> {
+        let snapshot = Snapshot {
+            aggregate_id: self.item_id,
+            version: self.version,
+            schema_version: SNAPSHOT_SCHEMA_VERSION,
+            data: serde_json::to_value(self)?,
+        };
+        event_store.save_snapshot(snapshot).await
+    }
+
+    /// 스냅샷 무효화 및 재생성
+    /// 기존 스냅샷을 모두 삭제한 뒤, 스냅샷 주기 이상 이벤트가 있는 상품은 전체 이벤트를 다시 적용하여 스냅샷 저장
+    pub async fn rebuild_snapshots(
+        event_store: &impl EventStore,
+        items: &impl ItemRepository,
+    ) -> Result<usize, AuctionError> {
+        let deleted = event_store.delete_snapshots().await?;
+        info!("{:<12} --> 스냅샷 삭제: {}건", "Aggregate", deleted);
+
+        let mut rebuilt = 0;
+        for item in items.get_all_items().await? {
+            let events = event_store.load_events(item.id, 0).await?;
+            let mut aggregate = Self::initial(items, item.id, &events).await?;
+            for event in &events {
+                aggregate.apply(event)?;
+            }
+            if aggregate.version >= snapshot_interval() {
+                aggregate.save_snapshot(event_store).await?;
+                rebuilt += 1;
+            }
+        }
+        info!("{:<12} --> 스냅샷 재생성: {}건", "Aggregate", rebuilt);
+        Ok(rebuilt)
+    }
+
+    /// 이벤트 적용
+    pub fn apply(&mut self, event: &Event) -> Result<(), AuctionError> {
+        let auction_event: AuctionEvent = serde_json::from_value(event.data.clone())?;
+        self.apply_auction_event(event.version, auction_event);
+        self.version = event.version;
+        Ok(())
+    }
+
+    /// 경매 이벤트 적용
+    /// 상태는 SCHEDULED -> ACTIVE -> 종료 상태 순으로만 변경 (경매 시작 전 철회 시 WITHDRAWN, 판매자 취소 시 CANCELLED)
+    fn apply_auction_event(&mut self, version: i64, auction_event: AuctionEvent) {
+        match auction_event {
+            AuctionEvent::BidPlaced {
+                bidder_id,
+                bid_amount,
+                timestamp,
+                ..
+            } => self.apply_bid(version, bidder_id, bid_amount, false, timestamp),
+            AuctionEvent::AutoBidPlaced {
+                bidder_id,
+                bid_amount,
+                timestamp,
+                ..
+            } => self.apply_bid(version, bidder_id, bid_amount, true, timestamp),
+            AuctionEvent::BuyNowExecuted {
+                buyer_id,
+                price,
+                timestamp,
+                ..
+            } => {
+                // 네덜란드식 경매는 현재 가격으로 낙찰되므로 현재 가격과 관계없이 입찰로 반영
+                self.current_price = price;
+                self.bids.push(AggregateBid {
+                    version,
+                    bidder_id: buyer_id,
+                    bid_amount: price,
+                    is_auto: false,
+                    timestamp,
+                });
+                self.status = "COMPLETED".to_string();
+                self.winner_id = Some(buyer_id);
+            }
+            AuctionEvent::ProxyBidRegistered {
+                bidder_id,
+                max_amount,
+                ..
+            } => {
+                self.proxy_bids.retain(|p| p.bidder_id != bidder_id);
+                self.proxy_bids.push(ProxyBid {
+                    bidder_id,
+                    max_amount,
+                    version,
+                });
+            }
+            AuctionEvent::BidRetracted {
+                bidder_id,
+                bid_version,
+                ..
+            } => {
+                // 철회한 입찰자의 자동 입찰 한도도 해제하고, 현재 가격은 남은 입찰 중 최고 금액으로 재계산
+                self.bids.retain(|bid| bid.version != bid_version);
+                self.proxy_bids.retain(|p| p.bidder_id != bidder_id);
+                self.current_price = self
+                    .bids
+                    .iter()
+                    .map(|bid| bid.bid_amount)
+                    .max()
+                    .unwrap_or(self.starting_price);
+            }
+            AuctionEvent::AuctionExtended { new_end_time, .. } => {
+                self.end_time = self.end_time.max(new_end_time);
+            }
+            AuctionEvent::SealedBidSubmitted {
+                bidder_id,
+                bid_amount,
+                timestamp,
+                ..
+            } => {
+                // 입찰 금액은 종료 전까지 비공개이므로 현재 가격은 변경하지 않음
+                self.bids.retain(|bid| bid.bidder_id != bidder_id);
+                self.bids.push(AggregateBid {
+                    version,
+                    bidder_id,
+                    bid_amount,
+                    is_auto: false,
+                    timestamp,
+                });
+            }
+            AuctionEvent::PriceDropped { new_price, .. } => {
+                if self.status == "ACTIVE" {
+                    self.current_price = new_price;
+                }
+            }
+            AuctionEvent::AuctionStarted { .. } => {
+                if self.status == "SCHEDULED" {
+                    self.status = "ACTIVE".to_string();
+                }
+            }
+            AuctionEvent::AuctionClosed {
+                winner_id,
+                final_price,
+                reserve_met,
+                ..
+            } => {
+                if self.is_open() {
+                    // 비공개 입찰 경매는 종료 시 낙찰가 공개
+                    if let Some(final_price) = final_price {
+                        self.current_price = final_price;
+                    }
+                    self.status = if reserve_met {
+                        "COMPLETED".to_string()
+                    } else {
+                        "UNSOLD_RESERVE_NOT_MET".to_string()
+                    };
+                    self.winner_id = winner_id;
+                }
+            }
+            AuctionEvent::ItemListed {
+                title,
+                description,
+                seller,
+                starting_price,
+                buy_now_price,
+                reserve_price,
+                bid_increments,
+                start_time,
+                end_time,
+                soft_close_window_secs,
+                soft_close_extension_secs,
+                auction_type,
+                price_drop_amount,
+                price_drop_interval_secs,
+                ..
+            } => {
+                self.seller = seller;
+                self.update_listing(ListingFields {
+                    title,
+                    description,
+                    starting_price,
+                    buy_now_price,
+                    reserve_price,
+                    bid_increments,
+                    start_time,
+                    end_time,
+                    soft_close_window_secs,
+                    soft_close_extension_secs,
+                    auction_type,
+                    price_drop_amount,
+                    price_drop_interval_secs,
+                });
+            }
+            AuctionEvent::ItemUpdated {
+                title,
+                description,
+                starting_price,
+                buy_now_price,
+                reserve_price,
+                bid_increments,
+                start_time,
+                end_time,
+                soft_close_window_secs,
+                soft_close_extension_secs,
+                auction_type,
+                price_drop_amount,
+                price_drop_interval_secs,
+                ..
+            } => {
+                if self.status == "SCHEDULED" {
+                    self.update_listing(ListingFields {
+                        title,
+                        description,
+                        starting_price,
+                        buy_now_price,
+                        reserve_price,
+                        bid_increments,
+                        start_time,
+                        end_time,
+                        soft_close_window_secs,
+                        soft_close_extension_secs,
+                        auction_type,
+                        price_drop_amount,
+                        price_drop_interval_secs,
+                    });
+                }
+            }
+            AuctionEvent::ItemWithdrawn { .. } => {
+                if self.status == "SCHEDULED" {
+                    self.status = "WITHDRAWN".to_string();
+                }
+            }
+            AuctionEvent::AuctionCancelled { .. } => {
+                if self.is_open() {
+                    self.status = "CANCELLED".to_string();
+                }
+            }
+        }
+    }
+
+    /// 현재 상품 등록 정보
+    pub fn listing(&self) -> ListingFields {
+        ListingFields {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            starting_price: self.starting_price,
+            buy_now_price: self.buy_now_price,
+            reserve_price: self.reserve_price,
+            bid_increments: self.bid_increments.clone(),
+            start_time: self.start_time,
+            end_time: self.end_time,
+            soft_close_window_secs: self.soft_close_window_secs,
+            soft_close_extension_secs: self.soft_close_extension_secs,
+            auction_type: self.auction_type.clone(),
+            price_drop_amount: self.price_drop_amount,
+            price_drop_interval_secs: self.price_drop_interval_secs,
+        }
+    }
+
+    /// 상품 등록 정보 반영 (입찰 전이므로 현재 가격은 시작 가격)
+    fn update_listing(&mut self, fields: ListingFields) {
+        self.title = fields.title;
+        self.description = fields.description;
+        self.starting_price = fields.starting_price;
+        self.current_price = fields.starting_price;
+        self.buy_now_price = fields.buy_now_price;
+        self.reserve_price = fields.reserve_price;
+        self.bid_increments = fields.bid_increments;
+        self.start_time = fields.start_time;
+        self.end_time = fields.end_time;
+        self.soft_close_window_secs = fields.soft_close_window_secs;
+        self.soft_close_extension_secs = fields.soft_close_extension_secs;
+        self.auction_type = fields.auction_type;
+        self.price_drop_amount = fields.price_drop_amount;
+        self.price_drop_interval_secs = fields.price_drop_interval_secs;
+    }
+
+    /// 입찰 적용 (현재 가격보다 높은 입찰만 반영)
+    fn apply_bid(
+        &mut self,
+        version: i64,
+        bidder_id: i64,
+        bid_amount: i64,
+        is_auto: bool,
+        timestamp: DateTime<Utc>,
+    ) {
+        if bid_amount < self.min_bid_amount() {
+            return;
+        }
+        self.current_price = bid_amount;
+        self.bids.push(AggregateBid {
+            version,
+            bidder_id,
+            bid_amount,
+            is_auto,
+            timestamp,
+        });
+    }
+
+    /// 종료되지 않은 경매인지 여부
+    pub fn is_open(&self) -> bool {
+        matches!(self.status.as_str(), "SCHEDULED" | "ACTIVE")
+    }
+
+    /// 네덜란드식(가격 하락식) 경매인지 여부
+    pub fn is_dutch(&self) -> bool {
+        self.auction_type == "DUTCH"
+    }
+
+    /// 비공개 입찰 경매(최고가 낙찰, 차가 낙찰)인지 여부
+    pub fn is_sealed(&self) -> bool {
+        matches!(
+            self.auction_type.as_str(),
+            "SEALED_FIRST_PRICE" | "SEALED_SECOND_PRICE"
+        )
+    }
+
+    /// 네덜란드식 경매의 일정상 가격
+    /// 시작 가격에서 경매 시작 후 하락 주기마다 하락 금액만큼 내리며, 최저 낙찰가(없으면 `DUTCH_FLOOR_PRICE`) 아래로는 내리지 않음
+    /// 스케줄러의 가격 하락 이벤트 기록은 늦을 수 있으므로 입찰 및 즉시 구매는 현재 가격 대신 일정상 가격 기준
+    pub fn scheduled_price(&self, now: DateTime<Utc>) -> i64 {
+        if self.price_drop_interval_secs <= 0 || now <= self.start_time {
+            return self.starting_price;
+        }
+        let drops = (now - self.start_time).num_seconds() / self.price_drop_interval_secs;
+        let floor_price = self.reserve_price.unwrap_or(DUTCH_FLOOR_PRICE);
+        self.starting_price
+            .saturating_sub(drops.saturating_mul(self.price_drop_amount))
+            .max(floor_price)
+    }
+
+    /// 최고 입찰 (동일 금액은 먼저 입찰한 입찰자 우선)
+    pub fn highest_bid(&self) -> Option<&AggregateBid> {
+        self.bids
+            .iter()
+            .min_by_key(|bid| (std::cmp::Reverse(bid.bid_amount), bid.version))
+    }
+
+    /// 낙찰가
+    /// 차가 비공개 입찰 경매(SEALED_SECOND_PRICE)는 두 번째로 높은 입찰 금액(최저 낙찰가 또는 시작 가격 이상), 그 외는 최고 입찰 금액
+    pub fn winning_price(&self, winning_bid: &AggregateBid) -> i64 {
+        if self.auction_type != "SEALED_SECOND_PRICE" {
+            return winning_bid.bid_amount;
+        }
+        let second_price = self
+            .bids
+            .iter()
+            .filter(|bid| bid.version != winning_bid.version)
+            .map(|bid| bid.bid_amount)
+            .max()
+            .unwrap_or(self.starting_price);
+        second_price.max(self.reserve_price.unwrap_or(self.starting_price))
+    }
+
+    /// 입찰자의 가장 최근 유효 입찰
+    pub fn latest_bid_of(&self, bidder_id: i64) -> Option<&AggregateBid> {
+        self.bids
+            .iter()
+            .rev()
+            .find(|bid| bid.bidder_id == bidder_id)
+    }
+
+    /// 입찰에 참여한 입찰자 id 목록 (중복 제거, 오름차순)
+    /// 자동 입찰만 등록한 입찰자도 포함
+    pub fn bidder_ids(&self) -> Vec<i64> {
+        let mut bidder_ids: Vec<i64> = self
+            .bids
+            .iter()
+            .map(|bid| bid.bidder_id)
+            .chain(self.proxy_bids.iter().map(|proxy_bid| proxy_bid.bidder_id))
+            .collect();
+        bidder_ids.sort_unstable();
+        bidder_ids.dedup();
+        bidder_ids
+    }
+
+    /// 최저 낙찰가 충족 여부 (조회 및 입찰 응답에 노출)
+    /// 현재 가격이 아닌 유효한 최고 입찰 기준이므로, 입찰이 없으면 최저 낙찰가가 시작 가격과 같아도 미충족
+    /// 비공개 입찰 경매는 종료 전까지 입찰 금액을 숨기므로 미충족으로 표시
+    pub fn is_reserve_met(&self) -> bool {
+        let Some(reserve_price) = self.reserve_price else {
+            return true;
+        };
+        if self.is_sealed() && matches!(self.status.as_str(), "SCHEDULED" | "ACTIVE") {
+            return false;
+        }
+        self.highest_bid()
+            .is_some_and(|bid| bid.bid_amount >= reserve_price)
+    }
+
+    /// 주어진 가격에서의 최소 증가 금액 (상품별 설정이 없으면 전역 설정)
+    pub fn min_increment(&self, price: i64) -> i64 {
+        let ladder = match &self.bid_increments {
+            Some(ladder) if !ladder.is_empty() => ladder.as_slice(),
+            _ => increment::global_ladder(),
+        };
+        increment::min_increment(ladder, price)
+    }
+
+    /// 현재 가격 기준 다음 입찰 가능 최소 금액 (입찰이 없으면 시작 가격)
+    pub fn next_acceptable_amount(&self) -> i64 {
+        if self.bids.is_empty() {
+            return self.current_price;
+        }
+        self.current_price + self.min_increment(self.current_price)
+    }
+
+    /// 입찰로 반영할 수 있는 최소 금액 (입찰이 없으면 현재 가격 이상, 있으면 현재 가격 초과)
+    pub fn min_bid_amount(&self) -> i64 {
+        if self.bids.is_empty() {
+            self.current_price
+        } else {
+            self.current_price + 1
+        }
+    }
+}
+
+// endregion: --- Auction Aggregate
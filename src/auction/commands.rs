@@ -0,0 +1,413 @@
+/// 상품 관리 커맨드 처리 (판매자의 상품 등록, 수정, 철회, 경매 취소)
+/// 상품 정보도 이벤트 저장소에 기록하며, 조회 모델(items)은 이벤트 소비자가 반영
+/// 1. 상품 등록: 새 상품 id를 발급하고 첫 이벤트(`ItemListed`) 저장
+/// 2. 상품 수정: 경매 시작 전(SCHEDULED)에만 가능, 지정한 항목만 변경
+/// 3. 상품 철회: 경매 시작 전(SCHEDULED)에만 가능
+/// 4. 경매 취소: 진행 중인 경매도 취소 가능, 사유 필수
+// region:    --- Imports
+use crate::auction::aggregate::{AuctionAggregate, ListingFields};
+use crate::auction::events::AuctionEvent;
+use crate::bidding::commands::new_event;
+use crate::bidding::increment::{self, IncrementStep};
+use crate::bidding::model::default_auction_type;
+use crate::error::AuctionError;
+use crate::event_store::EventStore;
+use crate::query::repository::ItemRepository;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use tracing::{info, warn};
+
+// endregion: --- Imports
+
+// region:    --- Commands
+/// 상품 등록 명령
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ListItemCommand {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// 등록한 판매자 (`X-Seller` 헤더)
+    #[serde(skip)]
+    pub seller: String,
+    pub starting_price: i64,
+    pub buy_now_price: i64,
+    /// 최저 낙찰가 (비공개)
+    #[serde(default)]
+    pub reserve_price: Option<i64>,
+    /// 상품별 입찰 단위 (없으면 전역 설정)
+    #[serde(default)]
+    pub bid_increments: Option<Vec<IncrementStep>>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    #[serde(default)]
+    pub soft_close_window_secs: i64,
+    #[serde(default)]
+    pub soft_close_extension_secs: i64,
+    /// 경매 방식 (ENGLISH: 오름차순, DUTCH: 가격 하락식, SEALED_FIRST_PRICE/SEALED_SECOND_PRICE: 비공개 입찰 최고가/차가 낙찰)
+    #[serde(default = "default_auction_type")]
+    pub auction_type: String,
+    /// 가격 하락 금액 (DUTCH)
+    #[serde(default)]
+    pub price_drop_amount: i64,
+    /// 가격 하락 주기(초) (DUTCH)
+    #[serde(default)]
+    pub price_drop_interval_secs: i64,
+    /// 멱등성 키 (`Idempotency-Key` 헤더), 명령으로 저장되는 이벤트에 함께 기록
+    #[serde(skip)]
+    pub idempotency_key: Option<String>,
+}
+
+/// 상품 수정 명령 (지정한 항목만 변경)
+/// 최저 낙찰가와 입찰 단위는 `null`을 지정하면 해제 (항목이 없으면 유지)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct UpdateItemCommand {
+    /// 수정할 상품 id (경로 매개변수)
+    #[serde(skip)]
+    pub item_id: i64,
+    /// 요청한 판매자 (`X-Seller` 헤더)
+    #[serde(skip)]
+    pub seller: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub starting_price: Option<i64>,
+    pub buy_now_price: Option<i64>,
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reserve_price: Option<Option<i64>>,
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bid_increments: Option<Option<Vec<IncrementStep>>>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub soft_close_window_secs: Option<i64>,
+    pub soft_close_extension_secs: Option<i64>,
+    pub auction_type: Option<String>,
+    pub price_drop_amount: Option<i64>,
+    pub price_drop_interval_secs: Option<i64>,
+    /// 멱등성 키 (`Idempotency-Key` 헤더), 명령으로 저장되는 이벤트에 함께 기록
+    #[serde(skip)]
+    pub idempotency_key: Option<String>,
+}
+
+/// 상품 철회 명령
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WithdrawItemCommand {
+    pub item_id: i64,
+    /// 요청한 판매자 (`X-Seller` 헤더)
+    #[serde(skip)]
+    pub seller: String,
+}
+
+/// 경매 취소 명령
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CancelAuctionCommand {
+    /// 취소할 상품 id (경로 매개변수)
+    #[serde(skip)]
+    pub item_id: i64,
+    /// 요청한 판매자 (`X-Seller` 헤더)
+    #[serde(skip)]
+    pub seller: String,
+    pub reason: String,
+}
+
+// 최대 재시도 횟수
+const MAX_RETRIES: i32 = 100;
+
+/// 1. 상품 등록
+pub async fn handle_list_item(
+    cmd: ListItemCommand,
+    event_store: &impl EventStore,
+) -> Result<AuctionAggregate, AuctionError> {
+    info!("{:<12} --> 상품 등록 요청 처리 시작: {:?}", "Command", cmd);
+    let now = Utc::now();
+
+    if cmd.seller.trim().is_empty() {
+        return Err(AuctionError::InvalidItem("item.seller_required"));
+    }
+    let listing = ListingFields {
+        title: cmd.title,
+        description: cmd.description,
+        starting_price: cmd.starting_price,
+        buy_now_price: cmd.buy_now_price,
+        reserve_price: cmd.reserve_price,
+        bid_increments: cmd.bid_increments,
+        start_time: cmd.start_time,
+        end_time: cmd.end_time,
+        soft_close_window_secs: cmd.soft_close_window_secs,
+        soft_close_extension_secs: cmd.soft_close_extension_secs,
+        auction_type: cmd.auction_type,
+        price_drop_amount: cmd.price_drop_amount,
+        price_drop_interval_secs: cmd.price_drop_interval_secs,
+    };
+    validate_listing(&listing, now)?;
+
+    // 새 상품이므로 버전 충돌 없이 첫 버전으로 저장
+    let item_id = event_store.next_aggregate_id().await?;
+    let listed_event = AuctionEvent::ItemListed {
+        item_id,
+        title: listing.title,
+        description: listing.description,
+        seller: cmd.seller,
+        starting_price: listing.starting_price,
+        buy_now_price: listing.buy_now_price,
+        reserve_price: listing.reserve_price,
+        bid_increments: listing.bid_increments,
+        start_time: listing.start_time,
+        end_time: listing.end_time,
+        soft_close_window_secs: listing.soft_close_window_secs,
+        soft_close_extension_secs: listing.soft_close_extension_secs,
+        auction_type: listing.auction_type,
+        price_drop_amount: listing.price_drop_amount,
+        price_drop_interval_secs: listing.price_drop_interval_secs,
+        timestamp: now,
+    };
+    let mut event = new_event(item_id, listed_event, 1, now)?;
+    event.idempotency_key = cmd.idempotency_key;
+    event_store.append_and_publish_event(event.clone()).await?;
+
+    let mut item = AuctionAggregate::unlisted(item_id);
+    item.apply(&event)?;
+    info!("{:<12} --> 상품 등록 완료: item_id={}", "Command", item_id);
+    Ok(item)
+}
+
+/// 2. 상품 수정
+pub async fn handle_update_item(
+    cmd: UpdateItemCommand,
+    event_store: &impl EventStore,
+    items: &impl ItemRepository,
+) -> Result<AuctionAggregate, AuctionError> {
+    info!("{:<12} --> 상품 수정 요청 처리 시작: {:?}", "Command", cmd);
+    execute(
+        event_store,
+        items,
+        cmd.item_id,
+        cmd.idempotency_key.clone(),
+        |item, now| {
+            ensure_seller(item, &cmd.seller)?;
+            ensure_scheduled(item)?;
+
+            let current = item.listing();
+            let listing = ListingFields {
+                title: cmd.title.clone().unwrap_or(current.title),
+                description: cmd.description.clone().unwrap_or(current.description),
+                starting_price: cmd.starting_price.unwrap_or(current.starting_price),
+                buy_now_price: cmd.buy_now_price.unwrap_or(current.buy_now_price),
+                reserve_price: cmd.reserve_price.unwrap_or(current.reserve_price),
+                bid_increments: cmd.bid_increments.clone().unwrap_or(current.bid_increments),
+                start_time: cmd.start_time.unwrap_or(current.start_time),
+                end_time: cmd.end_time.unwrap_or(current.end_time),
+                soft_close_window_secs: cmd
+                    .soft_close_window_secs
+                    .unwrap_or(current.soft_close_window_secs),
+                soft_close_extension_secs: cmd
+                    .soft_close_extension_secs
+                    .unwrap_or(current.soft_close_extension_secs),
+                auction_type: cmd.auction_type.clone().unwrap_or(current.auction_type),
+                price_drop_amount: cmd.price_drop_amount.unwrap_or(current.price_drop_amount),
+                price_drop_interval_secs: cmd
+                    .price_drop_interval_secs
+                    .unwrap_or(current.price_drop_interval_secs),
+            };
+            validate_listing(&listing, now)?;
+
+            Ok(AuctionEvent::ItemUpdated {
+                item_id: item.item_id,
+                title: listing.title,
+                description: listing.description,
+                starting_price: listing.starting_price,
+                buy_now_price: listing.buy_now_price,
+                reserve_price: listing.reserve_price,
+                bid_increments: listing.bid_increments,
+                start_time: listing.start_time,
+                end_time: listing.end_time,
+                soft_close_window_secs: listing.soft_close_window_secs,
+                soft_close_extension_secs: listing.soft_close_extension_secs,
+                auction_type: listing.auction_type,
+                price_drop_amount: listing.price_drop_amount,
+                price_drop_interval_secs: listing.price_drop_interval_secs,
+                timestamp: now,
+            })
+        },
+    )
+    .await
+}
+
+/// 3. 상품 철회
+pub async fn handle_withdraw_item(
+    cmd: WithdrawItemCommand,
+    event_store: &impl EventStore,
+    items: &impl ItemRepository,
+) -> Result<AuctionAggregate, AuctionError> {
+    info!("{:<12} --> 상품 철회 요청 처리 시작: {:?}", "Command", cmd);
+    execute(event_store, items, cmd.item_id, None, |item, now| {
+        ensure_seller(item, &cmd.seller)?;
+        ensure_scheduled(item)?;
+        Ok(AuctionEvent::ItemWithdrawn {
+            item_id: item.item_id,
+            timestamp: now,
+        })
+    })
+    .await
+}
+
+/// 4. 경매 취소 (종료 시간이 지난 경매는 종료 처리 대기 중이므로 취소 불가)
+pub async fn handle_cancel_auction(
+    cmd: CancelAuctionCommand,
+    event_store: &impl EventStore,
+    items: &impl ItemRepository,
+) -> Result<AuctionAggregate, AuctionError> {
+    info!("{:<12} --> 경매 취소 요청 처리 시작: {:?}", "Command", cmd);
+    let reason = cmd.reason.trim();
+    if reason.is_empty() {
+        return Err(AuctionError::CancelReasonRequired);
+    }
+
+    execute(event_store, items, cmd.item_id, None, |item, now| {
+        ensure_seller(item, &cmd.seller)?;
+        match item.status.as_str() {
+            "COMPLETED" | "UNSOLD_RESERVE_NOT_MET" => return Err(AuctionError::AlreadyEnded),
+            "ACTIVE" if now > item.end_time => return Err(AuctionError::AlreadyEnded),
+            "SCHEDULED" | "ACTIVE" => {}
+            status => return Err(AuctionError::InvalidStatus(status.to_string())),
+        }
+        Ok(AuctionEvent::AuctionCancelled {
+            item_id: item.item_id,
+            reason: reason.to_string(),
+            bidder_ids: item.bidder_ids(),
+            timestamp: now,
+        })
+    })
+    .await
+}
+
+// endregion: --- Commands
+
+// region:    --- Helpers
+
+/// 경매 애그리거트 기준으로 이벤트를 결정하여 현재 버전 + 1로 저장 (멱등성 키가 있으면 이벤트에 함께 기록)
+/// 버전 충돌 시 애그리거트를 다시 재구성하여 재검증
+async fn execute<F>(
+    event_store: &impl EventStore,
+    items: &impl ItemRepository,
+    item_id: i64,
+    idempotency_key: Option<String>,
+    decide: F,
+) -> Result<AuctionAggregate, AuctionError>
+where
+    F: Fn(&AuctionAggregate, DateTime<Utc>) -> Result<AuctionEvent, AuctionError>,
+{
+    let mut retries = 0;
+
+    while retries < MAX_RETRIES {
+        let mut item = AuctionAggregate::load(event_store, items, item_id).await?;
+        let now = Utc::now();
+
+        let auction_event = decide(&item, now)?;
+        let mut event = new_event(item_id, auction_event, item.version + 1, now)?;
+        event.idempotency_key = idempotency_key.clone();
+
+        match event_store.append_and_publish_event(event.clone()).await {
+            Ok(_) => {
+                info!(
+                    "{:<12} --> {} 이벤트가 성공적으로 저장되었습니다.",
+                    "Command", event.event_type
+                );
+                item.apply(&event)?;
+                return Ok(item);
+            }
+            Err(AuctionError::VersionConflict) => {
+                warn!(
+                    "{:<12} --> 낙관적 업데이트로 인한 버전 충돌: 재시도",
+                    "Command"
+                );
+                retries += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    Err(AuctionError::MaxRetriesExceeded)
+}
+
+/// 명시적 `null`과 항목 누락을 구분하는 역직렬화 (`Some(None)`: 해제, `None`: 유지)
+fn double_option<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// 요청자가 상품 판매자인지 확인
+fn ensure_seller(item: &AuctionAggregate, seller: &str) -> Result<(), AuctionError> {
+    let seller = seller.trim();
+    if seller.is_empty() || seller != item.seller {
+        return Err(AuctionError::NotItemSeller);
+    }
+    Ok(())
+}
+
+/// 경매 시작 전 상품인지 확인
+fn ensure_scheduled(item: &AuctionAggregate) -> Result<(), AuctionError> {
+    match item.status.as_str() {
+        "SCHEDULED" => Ok(()),
+        status => Err(AuctionError::InvalidStatus(status.to_string())),
+    }
+}
+
+/// 상품 등록 정보 검증
+/// 시작 가격 <= 최저 낙찰가 <= 즉시 구매 가격, 시작 시간 < 종료 시간
+/// 네덜란드식 경매의 최저 낙찰가는 가격 하락 하한이므로 시작 가격 이하이며, 즉시 구매 가격은 사용하지 않으므로 검증하지 않음
+fn validate_listing(listing: &ListingFields, now: DateTime<Utc>) -> Result<(), AuctionError> {
+    let dutch = listing.auction_type == "DUTCH";
+    let reason = if listing.title.trim().is_empty() {
+        "item.title_required"
+    } else if !matches!(
+        listing.auction_type.as_str(),
+        "ENGLISH" | "DUTCH" | "SEALED_FIRST_PRICE" | "SEALED_SECOND_PRICE"
+    ) {
+        "item.invalid_auction_type"
+    } else if listing.starting_price <= 0 {
+        "item.invalid_starting_price"
+    } else if !dutch && listing.starting_price > listing.buy_now_price {
+        "item.buy_now_below_starting_price"
+    } else if listing.reserve_price.is_some_and(|reserve_price| {
+        if dutch {
+            reserve_price <= 0 || reserve_price > listing.starting_price
+        } else {
+            reserve_price < listing.starting_price || reserve_price > listing.buy_now_price
+        }
+    }) {
+        "item.invalid_reserve_price"
+    } else if dutch
+        && (listing.price_drop_amount <= 0
+            || listing.price_drop_amount >= listing.starting_price
+            || listing.price_drop_interval_secs <= 0)
+    {
+        "item.invalid_price_drop"
+    } else if listing
+        .bid_increments
+        .as_ref()
+        .is_some_and(|ladder| increment::validate_ladder(ladder).is_err())
+    {
+        "item.invalid_bid_increments"
+    } else if listing.start_time >= listing.end_time {
+        "item.end_before_start"
+    } else if listing.end_time <= now {
+        "item.end_in_past"
+    } else if listing.soft_close_window_secs < 0 || listing.soft_close_extension_secs < 0 {
+        "item.invalid_soft_close"
+    } else {
+        return Ok(());
+    };
+    Err(AuctionError::InvalidItem(reason))
+}
+
+// endregion: --- Helpers
@@ -1,3 +1,5 @@
+use crate::bidding::increment::IncrementStep;
+use crate::bidding::model::default_auction_type;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -17,4 +19,137 @@ pub enum AuctionEvent {
         price: i64,
         timestamp: DateTime<Utc>,
     },
+    // 자동 입찰(최대 입찰가) 등록 이벤트
+    ProxyBidRegistered {
+        item_id: i64,
+        bidder_id: i64,
+        max_amount: i64,
+        timestamp: DateTime<Utc>,
+    },
+    // 입찰 철회 이벤트 (철회한 입찰의 이벤트 버전으로 식별)
+    BidRetracted {
+        item_id: i64,
+        bidder_id: i64,
+        bid_amount: i64,
+        bid_version: i64,
+        timestamp: DateTime<Utc>,
+    },
+    // 자동 입찰 이벤트
+    AutoBidPlaced {
+        item_id: i64,
+        bidder_id: i64,
+        bid_amount: i64,
+        timestamp: DateTime<Utc>,
+    },
+    // 경매 종료 시간 연장 이벤트 (마감 직전 입찰)
+    AuctionExtended {
+        item_id: i64,
+        previous_end_time: DateTime<Utc>,
+        new_end_time: DateTime<Utc>,
+        timestamp: DateTime<Utc>,
+    },
+    // 비공개 입찰 제출 이벤트 (입찰자별 하나의 입찰만 유지, 다시 제출하면 이전 입찰 대체)
+    SealedBidSubmitted {
+        item_id: i64,
+        bidder_id: i64,
+        bid_amount: i64,
+        timestamp: DateTime<Utc>,
+    },
+    // 가격 하락 이벤트 (네덜란드식 경매, 스케줄러가 가격 하락 일정에 따라 기록)
+    PriceDropped {
+        item_id: i64,
+        previous_price: i64,
+        new_price: i64,
+        timestamp: DateTime<Utc>,
+    },
+    // 경매 시작 이벤트
+    AuctionStarted {
+        item_id: i64,
+        timestamp: DateTime<Utc>,
+    },
+    // 경매 종료 이벤트 (낙찰자 결정)
+    AuctionClosed {
+        item_id: i64,
+        winner_id: Option<i64>,
+        final_price: Option<i64>,
+        reserve_met: bool,
+        timestamp: DateTime<Utc>,
+    },
+    // 상품 등록 이벤트 (경매 애그리거트의 첫 이벤트)
+    ItemListed {
+        item_id: i64,
+        title: String,
+        description: String,
+        seller: String,
+        starting_price: i64,
+        buy_now_price: i64,
+        reserve_price: Option<i64>,
+        bid_increments: Option<Vec<IncrementStep>>,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        soft_close_window_secs: i64,
+        soft_close_extension_secs: i64,
+        #[serde(default = "default_auction_type")]
+        auction_type: String,
+        #[serde(default)]
+        price_drop_amount: i64,
+        #[serde(default)]
+        price_drop_interval_secs: i64,
+        timestamp: DateTime<Utc>,
+    },
+    // 상품 정보 수정 이벤트 (경매 시작 전, 수정 후 전체 값)
+    ItemUpdated {
+        item_id: i64,
+        title: String,
+        description: String,
+        starting_price: i64,
+        buy_now_price: i64,
+        reserve_price: Option<i64>,
+        bid_increments: Option<Vec<IncrementStep>>,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        soft_close_window_secs: i64,
+        soft_close_extension_secs: i64,
+        #[serde(default = "default_auction_type")]
+        auction_type: String,
+        #[serde(default)]
+        price_drop_amount: i64,
+        #[serde(default)]
+        price_drop_interval_secs: i64,
+        timestamp: DateTime<Utc>,
+    },
+    // 상품 등록 철회 이벤트 (경매 시작 전)
+    ItemWithdrawn {
+        item_id: i64,
+        timestamp: DateTime<Utc>,
+    },
+    // 판매자의 경매 취소 이벤트 (알림 서비스가 입찰자 전원에게 알릴 수 있도록 입찰자 id 목록 포함)
+    AuctionCancelled {
+        item_id: i64,
+        reason: String,
+        bidder_ids: Vec<i64>,
+        timestamp: DateTime<Utc>,
+    },
+}
+
+impl AuctionEvent {
+    /// 이벤트 저장소에 기록되는 이벤트 타입
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            AuctionEvent::BidPlaced { .. } => "BidPlaced",
+            AuctionEvent::BuyNowExecuted { .. } => "BuyNowExecuted",
+            AuctionEvent::ProxyBidRegistered { .. } => "ProxyBidRegistered",
+            AuctionEvent::AutoBidPlaced { .. } => "AutoBidPlaced",
+            AuctionEvent::BidRetracted { .. } => "BidRetracted",
+            AuctionEvent::AuctionExtended { .. } => "AuctionExtended",
+            AuctionEvent::AuctionStarted { .. } => "AuctionStarted",
+            AuctionEvent::AuctionClosed { .. } => "AuctionClosed",
+            AuctionEvent::ItemListed { .. } => "ItemListed",
+            AuctionEvent::ItemUpdated { .. } => "ItemUpdated",
+            AuctionEvent::ItemWithdrawn { .. } => "ItemWithdrawn",
+            AuctionEvent::PriceDropped { .. } => "PriceDropped",
+            AuctionEvent::SealedBidSubmitted { .. } => "SealedBidSubmitted",
+            AuctionEvent::AuctionCancelled { .. } => "AuctionCancelled",
+        }
+    }
 }
@@ -1 +1,3 @@
+pub mod aggregate;
+pub mod commands;
 pub mod events;
@@ -1,13 +1,15 @@
-/// 입찰 관련 커맨드 처리
+/// 입찰 관련 커맨드 처리 (이벤트 저장소로부터 재구성한 경매 애그리거트 상태 기준으로 검증)
 /// 1. 입찰
 /// 2. 즉시 구매
+/// 3. 입찰 철회
 // region:    --- Imports
+use crate::auction::aggregate::AuctionAggregate;
 use crate::auction::events::AuctionEvent;
-use crate::database::DatabaseManager;
+use crate::bidding::retraction::RetractionPolicy;
+use crate::error::AuctionError;
 use crate::event_store::{Event, EventStore};
-use crate::query::handlers;
-use crate::query::handlers::get_item_version;
-use chrono::Utc;
+use crate::query::repository::ItemRepository;
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 // endregion: --- Imports
@@ -19,6 +21,12 @@ pub struct PlaceBidCommand {
     pub item_id: i64,
     pub bidder_id: i64,
     pub bid_amount: i64,
+    /// 자동 입찰 최대 금액(비공개), 경쟁 입찰 시 이 금액까지 자동으로 재입찰
+    #[serde(default)]
+    pub max_bid: Option<i64>,
+    /// 멱등성 키 (`Idempotency-Key` 헤더), 명령으로 저장되는 이벤트에 함께 기록
+    #[serde(skip)]
+    pub idempotency_key: Option<String>,
 }
 
 /// 즉시 구매 명령
@@ -26,6 +34,19 @@ pub struct PlaceBidCommand {
 pub struct BuyNowCommand {
     pub item_id: i64,
     pub buyer_id: i64,
+    /// 멱등성 키 (`Idempotency-Key` 헤더), 명령으로 저장되는 이벤트에 함께 기록
+    #[serde(skip)]
+    pub idempotency_key: Option<String>,
+}
+
+/// 입찰 철회 명령
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RetractBidCommand {
+    pub item_id: i64,
+    pub bidder_id: i64,
+    /// 멱등성 키 (`Idempotency-Key` 헤더), 명령으로 저장되는 이벤트에 함께 기록
+    #[serde(skip)]
+    pub idempotency_key: Option<String>,
 }
 
 // 최대 재시도 횟수
@@ -35,115 +56,75 @@ const MAX_RETRIES: i32 = 100;
 pub async fn handle_place_bid(
     cmd: PlaceBidCommand,
     event_store: &impl EventStore,
-    db_manager: &DatabaseManager,
-) -> Result<(), serde_json::Value> {
+    items: &impl ItemRepository,
+) -> Result<AuctionAggregate, AuctionError> {
     info!("{:<12} --> 입찰 요청 처리 시작: {:?}", "Command", cmd);
     let mut retries = 0;
 
     while retries < MAX_RETRIES {
-        // 현재 버전 조회
-        let current_version = get_item_version(db_manager, cmd.item_id)
-            .await
-            .map_err(|e| serde_json::json!({"error": e.to_string()}))?;
-
-        // 아이템 정보 조회
-        let item = handlers::get_item(db_manager, cmd.item_id)
-            .await
-            .map_err(|e| serde_json::json!({"error": e.to_string()}))?;
+        // 이벤트 저장소로부터 경매 상태 재구성
+        let mut item = AuctionAggregate::load(event_store, items, cmd.item_id).await?;
 
         let now = Utc::now();
 
-        // 경매 상태 및 시간 검증
-        if now < item.start_time {
-            return Err(serde_json::json!({
-                "error": "경매가 아직 시작되지 않았습니다.",
-                "code": "NOT_STARTED"
-            }));
+        // 경매 상태 및 시간 검증 (철회 또는 취소된 경매는 시작 시간과 관계없이 거절)
+        if now < item.start_time && item.is_open() {
+            return Err(AuctionError::NotStarted);
         }
 
         match item.status.as_str() {
-            "SCHEDULED" => {
-                return Err(
-                    serde_json::json!({"error": "경매가 아직 시작되지 않았습니다.", "code": "NOT_STARTED"}),
-                )
-            }
-            "COMPLETED" => {
-                return Err(
-                    serde_json::json!({"error": "경매가 이미 종료되었습니다.", "code": "ALREADY_ENDED"}),
-                )
-            }
-            _ if now > item.end_time => {
-                return Err(
-                    serde_json::json!({"error": "경매가 이미 종료되었습니다.", "code": "ALREADY_ENDED"}),
-                )
-            }
+            "SCHEDULED" => return Err(AuctionError::NotStarted),
+            "COMPLETED" | "UNSOLD_RESERVE_NOT_MET" => return Err(AuctionError::AlreadyEnded),
+            "WITHDRAWN" | "CANCELLED" => return Err(AuctionError::InvalidStatus(item.status)),
+            _ if now > item.end_time => return Err(AuctionError::AlreadyEnded),
             "ACTIVE" if now <= item.end_time => {
-                if cmd.bid_amount <= item.current_price {
-                    return Err(serde_json::json!({
-                        "error": "입찰 금액이 현재 가격보다 낮습니다.",
-                        "code": "LOW_BID",
-                        "bid_amount": cmd.bid_amount,
-                    }));
-                }
+                let mut events = if item.is_dutch() {
+                    accept_dutch_price(&cmd, &item, now)?
+                } else if item.is_sealed() {
+                    submit_sealed_bid(&cmd, &item, now)?
+                } else {
+                    // 첫 입찰은 시작 가격 이상, 이후 입찰은 현재 가격 초과
+                    if cmd.bid_amount < item.min_bid_amount() {
+                        return Err(AuctionError::LowBid {
+                            bid_amount: cmd.bid_amount,
+                        });
+                    }
 
-                // 입찰 금액이 즉시구매 가격 이상인 경우 낙찰 처리
-                if cmd.bid_amount >= item.buy_now_price {
-                    let buy_now_event = AuctionEvent::BuyNowExecuted {
-                        item_id: cmd.item_id,
-                        buyer_id: cmd.bidder_id,
-                        price: item.buy_now_price, // 입찰가 대신 즉시구매 가격으로 처리
-                        timestamp: now,
-                    };
-
-                    let event = Event {
-                        id: 0,
-                        aggregate_id: cmd.item_id,
-                        event_type: "BuyNowExecuted".to_string(),
-                        data: serde_json::to_value(buy_now_event)
-                            .map_err(|e| serde_json::json!({"error": e.to_string()}))?,
-                        timestamp: now,
-                        version: current_version + 1,
-                    };
-
-                    // 이벤트 저장 및 발행
-                    match event_store.append_and_publish_event(event).await {
-                        Ok(_) => {
-                            info!(
-                                "{:<12} --> BuyNowExecuted 이벤트가 성공적으로 저장되었습니다.",
-                                "Command"
-                            );
-                            return Ok(());
-                        }
-                        Err(e) if e.contains("버전 충돌") => {
-                            retries += 1;
-                            continue;
-                        }
-                        Err(e) => return Err(serde_json::json!({"error": e})),
+                    // 최소 입찰 단위 검증 (즉시 구매 가격 이상 입찰은 제외)
+                    let next_acceptable_amount = item.next_acceptable_amount();
+                    if cmd.bid_amount < item.buy_now_price
+                        && cmd.bid_amount < next_acceptable_amount
+                    {
+                        return Err(AuctionError::BidIncrementTooSmall {
+                            bid_amount: cmd.bid_amount,
+                            next_acceptable_amount,
+                        });
                     }
-                }
 
-                // 입찰 이벤트 생성
-                let bid_event = AuctionEvent::BidPlaced {
-                    item_id: cmd.item_id,
-                    bidder_id: cmd.bidder_id,
-                    bid_amount: cmd.bid_amount,
-                    timestamp: now,
-                };
+                    if let Some(max_bid) = cmd.max_bid {
+                        if max_bid < cmd.bid_amount {
+                            return Err(AuctionError::InvalidMaxBid {
+                                bid_amount: cmd.bid_amount,
+                                max_bid,
+                            });
+                        }
+                    }
 
-                let event = Event {
-                    id: 0,
-                    aggregate_id: cmd.item_id,
-                    event_type: "BidPlaced".to_string(),
-                    data: serde_json::to_value(bid_event)
-                        .map_err(|e| serde_json::json!({"error": e.to_string()}))?,
-                    timestamp: now,
-                    version: current_version + 1,
+                    build_bid_events(&cmd, &item, now)?
                 };
+                for event in &mut events {
+                    event.idempotency_key = cmd.idempotency_key.clone();
+                }
 
                 // 이벤트 저장 및 발행
-                match event_store.append_and_publish_event(event).await {
-                    Ok(_) => return Ok(()),
-                    Err(e) if e.contains("버전 충돌") => {
+                match event_store.append_and_publish_events(events.clone()).await {
+                    Ok(_) => {
+                        for event in &events {
+                            item.apply(event)?;
+                        }
+                        return Ok(item);
+                    }
+                    Err(AuctionError::VersionConflict) => {
                         warn!(
                             "{:<12} --> 낙관적 업데이트로 인한 버전 충돌: 재시도",
                             "Command"
@@ -151,111 +132,482 @@ pub async fn handle_place_bid(
                         retries += 1;
                         continue;
                     }
-                    Err(e) => return Err(serde_json::json!({"error": e})),
+                    Err(e) => return Err(e),
                 }
             }
-            _ => {
-                return Err(
-                    serde_json::json!({"error": "잘못된 경매 상태입니다.", "code": "INVALID_STATUS"}),
-                )
-            }
+            status => return Err(AuctionError::InvalidStatus(status.to_string())),
         }
     }
 
-    Err(serde_json::json!({"error": "최대 재시도 횟수 초과", "code": "MAX_RETRIES_EXCEEDED"}))
+    Err(AuctionError::MaxRetriesExceeded)
 }
 
 /// 2. 즉시 구매(낙찰)
 pub async fn handle_buy_now(
     cmd: BuyNowCommand,
-    buy_now_price: i64,
     event_store: &impl EventStore,
-    db_manager: &DatabaseManager,
-) -> Result<(), serde_json::Value> {
+    items: &impl ItemRepository,
+) -> Result<AuctionAggregate, AuctionError> {
     info!("{:<12} --> 즉시 구매 요청 처리 시작: {:?}", "Command", cmd);
     let mut retries = 0;
 
     while retries < MAX_RETRIES {
-        // 현재 버전 조회
-        let current_version = get_item_version(db_manager, cmd.item_id)
-            .await
-            .map_err(|e| serde_json::json!({"error": e.to_string()}))?;
-
-        // 아이템 정보 조회
-        let item = handlers::get_item(db_manager, cmd.item_id)
-            .await
-            .map_err(|e| serde_json::json!({"error": e.to_string()}))?;
+        // 이벤트 저장소로부터 경매 상태 재구성
+        let mut item = AuctionAggregate::load(event_store, items, cmd.item_id).await?;
 
         let now = Utc::now();
 
-        // 경매 상태 및 시간 검증
-        if now < item.start_time {
-            return Err(serde_json::json!({
-                "error": "경매가 아직 시작되지 않았습니다.",
-                "code": "NOT_STARTED"
-            }));
+        // 경매 상태 및 시간 검증 (철회 또는 취소된 경매는 시작 시간과 관계없이 거절)
+        if now < item.start_time && item.is_open() {
+            return Err(AuctionError::NotStarted);
         }
 
         match item.status.as_str() {
-            "SCHEDULED" => {
-                return Err(
-                    serde_json::json!({"error": "경매가 아직 시작되지 않았습니다.", "code": "NOT_STARTED"}),
-                )
-            }
-            "COMPLETED" => {
-                return Err(
-                    serde_json::json!({"error": "경매가 이미 종료되었습니다.", "code": "ALREADY_ENDED"}),
-                )
-            }
-            _ if now > item.end_time => {
-                return Err(
-                    serde_json::json!({"error": "경매가 이미 종료되었습니다.", "code": "ALREADY_ENDED"}),
-                )
-            }
+            "SCHEDULED" => return Err(AuctionError::NotStarted),
+            "COMPLETED" | "UNSOLD_RESERVE_NOT_MET" => return Err(AuctionError::AlreadyEnded),
+            "WITHDRAWN" | "CANCELLED" => return Err(AuctionError::InvalidStatus(item.status)),
+            _ if now > item.end_time => return Err(AuctionError::AlreadyEnded),
+            _ if item.is_sealed() => return Err(AuctionError::BuyNowNotSupported),
             "ACTIVE" if now <= item.end_time => {
-                // 즉시 구매 이벤트 생성
+                // 즉시 구매 이벤트 생성 (네덜란드식 경매는 일정상 가격으로 구매)
+                let price = if item.is_dutch() {
+                    item.scheduled_price(now)
+                } else {
+                    item.buy_now_price
+                };
                 let buy_now_event = AuctionEvent::BuyNowExecuted {
                     item_id: cmd.item_id,
                     buyer_id: cmd.buyer_id,
-                    price: buy_now_price,
+                    price,
                     timestamp: now,
                 };
 
-                let event = Event {
-                    id: 0,
-                    aggregate_id: cmd.item_id,
-                    event_type: "BuyNowExecuted".to_string(),
-                    data: serde_json::to_value(buy_now_event)
-                        .map_err(|e| serde_json::json!({"error": e.to_string()}))?,
-                    timestamp: now,
-                    version: current_version + 1, // 현재 이벤트 버전 + 1
-                };
+                // 현재 이벤트 버전 + 1
+                let mut event = new_event(cmd.item_id, buy_now_event, item.version + 1, now)?;
+                event.idempotency_key = cmd.idempotency_key.clone();
 
                 // 이벤트 저장 및 발행
-                match event_store.append_and_publish_event(event).await {
+                match event_store.append_and_publish_event(event.clone()).await {
                     Ok(_) => {
                         info!(
                             "{:<12} --> BuyNowExecuted 이벤트가 성공적으로 저장되었습니다.",
                             "Command"
                         );
-                        return Ok(());
+                        item.apply(&event)?;
+                        return Ok(item);
                     }
-                    Err(e) if e.contains("버전 충돌") => {
+                    Err(AuctionError::VersionConflict) => {
                         retries += 1;
                         continue;
                     }
-                    Err(e) => return Err(serde_json::json!({"error": e})),
+                    Err(e) => return Err(e),
                 }
             }
-            _ => {
-                return Err(
-                    serde_json::json!({"error": "잘못된 경매 상태입니다.", "code": "INVALID_STATUS"}),
-                )
+            status => return Err(AuctionError::InvalidStatus(status.to_string())),
+        }
+    }
+
+    Err(AuctionError::MaxRetriesExceeded)
+}
+
+/// 3. 입찰 철회 (입찰자의 가장 최근 유효 입찰을 철회 정책 범위 내에서만 철회, 자동 입찰 한도도 함께 해제)
+pub async fn handle_retract_bid(
+    cmd: RetractBidCommand,
+    event_store: &impl EventStore,
+    items: &impl ItemRepository,
+) -> Result<AuctionAggregate, AuctionError> {
+    info!("{:<12} --> 입찰 철회 요청 처리 시작: {:?}", "Command", cmd);
+    let policy = RetractionPolicy::global();
+    let mut retries = 0;
+
+    while retries < MAX_RETRIES {
+        // 이벤트 저장소로부터 경매 상태 재구성
+        let mut item = AuctionAggregate::load(event_store, items, cmd.item_id).await?;
+
+        let now = Utc::now();
+
+        match item.status.as_str() {
+            "SCHEDULED" => return Err(AuctionError::NotStarted),
+            "COMPLETED" | "UNSOLD_RESERVE_NOT_MET" => return Err(AuctionError::AlreadyEnded),
+            "WITHDRAWN" | "CANCELLED" => return Err(AuctionError::InvalidStatus(item.status)),
+            _ if now > item.end_time => return Err(AuctionError::AlreadyEnded),
+            // 비공개 입찰은 철회 대신 다시 제출하여 변경 (철회 후 현재 가격 재계산으로 입찰 금액이 공개되지 않도록)
+            _ if item.is_sealed() => {
+                return Err(AuctionError::RetractionNotAllowed("retraction.sealed_bid"))
+            }
+            "ACTIVE" => {
+                let bid = item
+                    .latest_bid_of(cmd.bidder_id)
+                    .ok_or(AuctionError::RetractionNotAllowed("retraction.no_bid"))?;
+                policy.check(bid.timestamp, item.end_time, now)?;
+
+                let retracted_event = AuctionEvent::BidRetracted {
+                    item_id: cmd.item_id,
+                    bidder_id: cmd.bidder_id,
+                    bid_amount: bid.bid_amount,
+                    bid_version: bid.version,
+                    timestamp: now,
+                };
+                let mut events = build_retraction_events(&item, retracted_event, now)?;
+                for event in &mut events {
+                    event.idempotency_key = cmd.idempotency_key.clone();
+                }
+
+                // 이벤트 저장 및 발행
+                match event_store.append_and_publish_events(events.clone()).await {
+                    Ok(_) => {
+                        info!(
+                            "{:<12} --> BidRetracted 이벤트가 성공적으로 저장되었습니다.",
+                            "Command"
+                        );
+                        for event in &events {
+                            item.apply(event)?;
+                        }
+                        return Ok(item);
+                    }
+                    Err(AuctionError::VersionConflict) => {
+                        retries += 1;
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
             }
+            status => return Err(AuctionError::InvalidStatus(status.to_string())),
         }
     }
 
-    Err(serde_json::json!({"error": "최대 재시도 횟수 초과", "code": "MAX_RETRIES_EXCEEDED"}))
+    Err(AuctionError::MaxRetriesExceeded)
 }
 
 // endregion: --- Commands
+
+// region:    --- Dutch Auction
+
+/// 네덜란드식 경매 가격 수락
+/// 입찰 금액이 일정상 가격 이상이면 일정상 가격으로 즉시 구매 처리 (먼저 수락한 입찰자 낙찰, 자동 입찰은 사용 불가)
+fn accept_dutch_price(
+    cmd: &PlaceBidCommand,
+    item: &AuctionAggregate,
+    now: DateTime<Utc>,
+) -> Result<Vec<Event>, AuctionError> {
+    if cmd.max_bid.is_some() {
+        return Err(AuctionError::ProxyBidNotSupported);
+    }
+    let price = item.scheduled_price(now);
+    if cmd.bid_amount < price {
+        return Err(AuctionError::LowBid {
+            bid_amount: cmd.bid_amount,
+        });
+    }
+
+    let accepted_event = AuctionEvent::BuyNowExecuted {
+        item_id: cmd.item_id,
+        buyer_id: cmd.bidder_id,
+        price,
+        timestamp: now,
+    };
+    Ok(vec![new_event(
+        cmd.item_id,
+        accepted_event,
+        item.version + 1,
+        now,
+    )?])
+}
+
+// endregion: --- Dutch Auction
+
+// region:    --- Sealed Bid Auction
+
+/// 비공개 입찰 제출
+/// 시작 가격 이상이면 현재 가격과 관계없이 제출하며, 같은 입찰자가 다시 제출하면 이전 입찰 대체
+/// 경매 종료 전까지 다른 입찰을 알 수 없으므로 최소 입찰 단위, 즉시 구매 전환, 경매 연장은 적용하지 않음
+fn submit_sealed_bid(
+    cmd: &PlaceBidCommand,
+    item: &AuctionAggregate,
+    now: DateTime<Utc>,
+) -> Result<Vec<Event>, AuctionError> {
+    if cmd.max_bid.is_some() {
+        return Err(AuctionError::ProxyBidNotSupported);
+    }
+    if cmd.bid_amount < item.starting_price {
+        return Err(AuctionError::LowBid {
+            bid_amount: cmd.bid_amount,
+        });
+    }
+
+    let submitted_event = AuctionEvent::SealedBidSubmitted {
+        item_id: cmd.item_id,
+        bidder_id: cmd.bidder_id,
+        bid_amount: cmd.bid_amount,
+        timestamp: now,
+    };
+    Ok(vec![new_event(
+        cmd.item_id,
+        submitted_event,
+        item.version + 1,
+        now,
+    )?])
+}
+
+// endregion: --- Sealed Bid Auction
+
+// region:    --- Proxy Bidding
+
+/// 입찰 명령으로부터 저장할 이벤트 목록 생성
+/// 1. 최대 입찰가가 있으면 자동 입찰 등록
+/// 2. 입찰 (즉시 구매 가격 이상이면 즉시 구매로 처리)
+/// 3. 경쟁 자동 입찰이 있으면 최대 입찰가 한도 내에서 자동 재입찰 (한도가 같으면 먼저 등록한 자동 입찰이 한도 금액으로 우선)
+/// 4. 마감 직전 입찰이면 경매 종료 시간 연장
+/// 5. 현재 가격을 올리는 입찰만 기록 (같은 금액의 입찰은 먼저 입찰한 입찰자 우선)
+fn build_bid_events(
+    cmd: &PlaceBidCommand,
+    item: &AuctionAggregate,
+    now: DateTime<Utc>,
+) -> Result<Vec<Event>, AuctionError> {
+    let mut auction_events = Vec::new();
+
+    if let Some(max_amount) = cmd.max_bid {
+        auction_events.push(AuctionEvent::ProxyBidRegistered {
+            item_id: cmd.item_id,
+            bidder_id: cmd.bidder_id,
+            max_amount,
+            timestamp: now,
+        });
+    }
+
+    let closed = push_proxy_bids(
+        &mut auction_events,
+        item,
+        cmd.bidder_id,
+        cmd.bid_amount,
+        false,
+        cmd.max_bid,
+        now,
+    );
+
+    // 마감 직전 입찰 시 종료 시간 연장 (스나이핑 방지)
+    if !closed {
+        if let Some(extended) = extend_end_time(item, now) {
+            auction_events.push(extended);
+        }
+    }
+
+    auction_events
+        .into_iter()
+        .zip(item.version + 1..)
+        .map(|(auction_event, version)| new_event(cmd.item_id, auction_event, version, now))
+        .collect()
+}
+
+/// 입찰 철회 이벤트 목록 생성
+/// 철회 후 현재 가격이 내려가면 최고 입찰자가 아닌 입찰자 중 가장 높은 한도의 자동 입찰이 다시 경쟁
+/// (입찰이 없으면 시작 가격, 있으면 다음 입찰 가능 금액으로 자동 입찰하며 경매 종료 시간은 연장하지 않음)
+fn build_retraction_events(
+    item: &AuctionAggregate,
+    retracted_event: AuctionEvent,
+    now: DateTime<Utc>,
+) -> Result<Vec<Event>, AuctionError> {
+    let retracted = new_event(item.item_id, retracted_event.clone(), item.version + 1, now)?;
+    let mut remaining = item.clone();
+    remaining.apply(&retracted)?;
+
+    let mut auction_events = vec![retracted_event];
+    let leader = remaining.highest_bid().map(|bid| bid.bidder_id);
+    let challenger = remaining
+        .proxy_bids
+        .iter()
+        .filter(|p| Some(p.bidder_id) != leader && p.max_amount >= remaining.min_bid_amount())
+        .min_by_key(|p| (std::cmp::Reverse(p.max_amount), p.version));
+    if let Some(challenger) = challenger {
+        let amount = match leader {
+            None => remaining.starting_price,
+            Some(_) => challenger
+                .max_amount
+                .min(remaining.next_acceptable_amount()),
+        };
+        push_proxy_bids(
+            &mut auction_events,
+            &remaining,
+            challenger.bidder_id,
+            amount,
+            true,
+            None,
+            now,
+        );
+    }
+
+    auction_events
+        .into_iter()
+        .zip(item.version + 1..)
+        .map(|(auction_event, version)| new_event(item.item_id, auction_event, version, now))
+        .collect()
+}
+
+/// 입찰과 경쟁 자동 입찰의 재입찰 이벤트 추가 (즉시 구매로 종료되면 true)
+/// 경쟁 자동 입찰이 있으면 최대 입찰가 한도 내에서 자동 재입찰 (한도가 같으면 먼저 등록한 자동 입찰이 한도 금액으로 우선)
+/// 현재 가격을 올리는 입찰만 기록 (같은 금액의 입찰은 먼저 입찰한 입찰자 우선)
+fn push_proxy_bids(
+    auction_events: &mut Vec<AuctionEvent>,
+    item: &AuctionAggregate,
+    bidder_id: i64,
+    bid_amount: i64,
+    is_auto: bool,
+    max_bid: Option<i64>,
+    now: DateTime<Utc>,
+) -> bool {
+    let proxy_bids = &item.proxy_bids;
+
+    // 입찰자의 한도: 이번 최대 입찰가, 기존 자동 입찰 한도, 입찰 금액 중 최대값
+    let bidder_max = proxy_bids
+        .iter()
+        .filter(|p| p.bidder_id == bidder_id)
+        .map(|p| p.max_amount)
+        .chain(max_bid)
+        .fold(bid_amount, i64::max);
+
+    // 가장 높은 한도의 경쟁 자동 입찰 (동일 한도는 먼저 등록한 입찰자 우선)
+    let rival = proxy_bids
+        .iter()
+        .filter(|p| p.bidder_id != bidder_id && p.max_amount >= bid_amount)
+        .min_by_key(|p| (std::cmp::Reverse(p.max_amount), p.version));
+
+    // 입찰 순서대로 (입찰자 id, 금액, 자동 입찰 여부)
+    let bids = match rival {
+        None => vec![(bidder_id, bid_amount, is_auto)],
+        Some(rival) => {
+            // 한도가 같으면 먼저 등록한 자동 입찰 우선 (이번에 등록하는 최대 입찰가는 기존 자동 입찰보다 늦음)
+            let bidder_registered_first = proxy_bids
+                .iter()
+                .filter(|p| p.bidder_id == bidder_id && p.max_amount == bidder_max)
+                .any(|p| p.version < rival.version);
+            let rival_wins = rival.max_amount > bidder_max
+                || (rival.max_amount == bidder_max && !bidder_registered_first);
+
+            if rival_wins && rival.max_amount == bid_amount {
+                // 경쟁자 한도와 같은 금액의 입찰: 먼저 등록한 경쟁자가 한도 금액으로 우선
+                vec![(rival.bidder_id, rival.max_amount, true)]
+            } else if rival_wins {
+                // 경쟁자 한도가 더 높음: 경쟁자가 입찰자 한도보다 한 단계 높게 자동 입찰
+                let amount = rival
+                    .max_amount
+                    .min(bidder_max + item.min_increment(bidder_max));
+                vec![
+                    (bidder_id, bid_amount, is_auto),
+                    (rival.bidder_id, amount, true),
+                ]
+            } else {
+                // 입찰자 한도가 더 높음: 경쟁자 한도까지 자동 입찰 후 입찰자가 한 단계 높게 자동 입찰
+                // 한도가 같고 입찰자가 먼저 등록했으면 경쟁자 입찰 없이 입찰자가 한도 금액으로 자동 입찰
+                let amount =
+                    bidder_max.min(rival.max_amount + item.min_increment(rival.max_amount));
+                let mut bids = vec![(bidder_id, bid_amount, is_auto)];
+                if rival.max_amount < bidder_max {
+                    bids.push((rival.bidder_id, rival.max_amount, true));
+                }
+                bids.push((bidder_id, amount, true));
+                bids
+            }
+        }
+    };
+
+    // 현재 가격을 올리는 입찰만 기록 (즉시 구매 가격 이상이면 즉시 구매 후 중단)
+    let mut min_amount = item.min_bid_amount();
+    for (bidder_id, bid_amount, is_auto) in bids {
+        if bid_amount < min_amount {
+            continue;
+        }
+        min_amount = bid_amount + 1;
+        if push_bid(
+            auction_events,
+            item.item_id,
+            bidder_id,
+            bid_amount,
+            item.buy_now_price,
+            is_auto,
+            now,
+        ) {
+            return true;
+        }
+    }
+    false
+}
+
+/// 경매 종료 시간 연장 이벤트 생성
+/// 종료 전 연장 구간(soft_close_window_secs) 내 입찰이면 종료 시간을 연장 시간만큼 연장
+fn extend_end_time(item: &AuctionAggregate, now: DateTime<Utc>) -> Option<AuctionEvent> {
+    if item.soft_close_window_secs <= 0 || item.soft_close_extension_secs <= 0 {
+        return None;
+    }
+
+    let window = Duration::seconds(item.soft_close_window_secs);
+    if item.end_time - now > window {
+        return None;
+    }
+
+    Some(AuctionEvent::AuctionExtended {
+        item_id: item.item_id,
+        previous_end_time: item.end_time,
+        new_end_time: item.end_time + Duration::seconds(item.soft_close_extension_secs),
+        timestamp: now,
+    })
+}
+
+/// 입찰 이벤트 추가
+/// 입찰 금액이 즉시 구매 가격 이상이면 즉시 구매 이벤트를 추가하고 true 반환
+fn push_bid(
+    auction_events: &mut Vec<AuctionEvent>,
+    item_id: i64,
+    bidder_id: i64,
+    bid_amount: i64,
+    buy_now_price: i64,
+    is_auto: bool,
+    now: DateTime<Utc>,
+) -> bool {
+    if bid_amount >= buy_now_price {
+        auction_events.push(AuctionEvent::BuyNowExecuted {
+            item_id,
+            buyer_id: bidder_id,
+            price: buy_now_price, // 입찰가 대신 즉시구매 가격으로 처리
+            timestamp: now,
+        });
+        return true;
+    }
+
+    auction_events.push(if is_auto {
+        AuctionEvent::AutoBidPlaced {
+            item_id,
+            bidder_id,
+            bid_amount,
+            timestamp: now,
+        }
+    } else {
+        AuctionEvent::BidPlaced {
+            item_id,
+            bidder_id,
+            bid_amount,
+            timestamp: now,
+        }
+    });
+    false
+}
+
+/// 이벤트 저장소에 저장할 이벤트 생성
+pub(crate) fn new_event(
+    item_id: i64,
+    auction_event: AuctionEvent,
+    version: i64,
+    now: DateTime<Utc>,
+) -> Result<Event, AuctionError> {
+    Ok(Event {
+        id: 0,
+        aggregate_id: item_id,
+        event_type: auction_event.event_type().to_string(),
+        data: serde_json::to_value(auction_event)?,
+        timestamp: now,
+        version,
+        idempotency_key: None,
+        position: 0,
+    })
+}
+
+// endregion: --- Proxy Bidding
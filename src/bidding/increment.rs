@@ -0,0 +1,115 @@
+/// 최소 입찰 단위(호가 단위) 관리
+/// 현재 가격 구간별로 다음 입찰에 필요한 최소 증가 금액을 정의
+/// 전역 설정은 `BID_INCREMENT_LADDER` 환경 변수로 지정하며, 상품별로 재정의 가능
+// region:    --- Imports
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use tracing::warn;
+
+// endregion: --- Imports
+
+// region:    --- Increment Ladder
+/// 입찰 단위 구간: 현재 가격이 `below` 미만이면 `increment` 이상 올려서 입찰해야 함
+/// `below`가 없으면 나머지 모든 가격 구간에 적용 (마지막 구간에만 사용 가능)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncrementStep {
+    pub below: Option<i64>,
+    pub increment: i64,
+}
+
+/// 기본 입찰 단위 (원)
+const DEFAULT_LADDER: [(Option<i64>, i64); 4] = [
+    (Some(10_000), 500),
+    (Some(100_000), 1_000),
+    (Some(1_000_000), 5_000),
+    (None, 10_000),
+];
+
+static GLOBAL_LADDER: OnceLock<Vec<IncrementStep>> = OnceLock::new();
+
+/// 전역 입찰 단위 조회
+/// `BID_INCREMENT_LADDER` 형식: "10000:500,100000:1000,*:5000" (가격 상한:증가 금액)
+pub fn global_ladder() -> &'static [IncrementStep] {
+    GLOBAL_LADDER.get_or_init(|| {
+        std::env::var("BID_INCREMENT_LADDER")
+            .ok()
+            .and_then(|value| match parse_ladder(&value) {
+                Ok(ladder) => Some(ladder),
+                Err(e) => {
+                    warn!(
+                        "{:<12} --> BID_INCREMENT_LADDER 설정 오류, 기본값 사용: {}",
+                        "Increment", e
+                    );
+                    None
+                }
+            })
+            .unwrap_or_else(|| {
+                DEFAULT_LADDER
+                    .iter()
+                    .map(|&(below, increment)| IncrementStep { below, increment })
+                    .collect()
+            })
+    })
+}
+
+/// 입찰 단위 설정 문자열 파싱 (가격 상한 오름차순으로 정렬 후 검증)
+pub fn parse_ladder(value: &str) -> Result<Vec<IncrementStep>, String> {
+    let mut ladder = value
+        .split(',')
+        .map(|step| {
+            let (below, increment) = step
+                .trim()
+                .split_once(':')
+                .ok_or_else(|| format!("잘못된 입찰 단위 구간: {}", step))?;
+            let below = match below.trim() {
+                "*" => None,
+                below => Some(below.parse::<i64>().map_err(|e| e.to_string())?),
+            };
+            let increment = increment.trim().parse::<i64>().map_err(|e| e.to_string())?;
+            if increment <= 0 {
+                return Err(format!("입찰 단위는 0보다 커야 합니다: {}", step));
+            }
+            Ok(IncrementStep { below, increment })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    // 가격 상한 오름차순 정렬 (상한 없는 구간은 마지막)
+    ladder.sort_by_key(|step| step.below.unwrap_or(i64::MAX));
+    validate_ladder(&ladder)?;
+    Ok(ladder)
+}
+
+/// 입찰 단위 구간 검증
+/// 1. 구간이 하나 이상
+/// 2. 증가 금액은 0보다 큼
+/// 3. 가격 상한은 0보다 크고 엄격한 오름차순 (상한 없는 구간은 마지막에만)
+pub fn validate_ladder(ladder: &[IncrementStep]) -> Result<(), String> {
+    if ladder.is_empty() {
+        return Err("입찰 단위 구간이 없습니다".to_string());
+    }
+
+    let mut previous_below = 0;
+    for (index, step) in ladder.iter().enumerate() {
+        if step.increment <= 0 {
+            return Err(format!("입찰 단위는 0보다 커야 합니다: {:?}", step));
+        }
+        match step.below {
+            Some(below) if below > previous_below => previous_below = below,
+            None if index == ladder.len() - 1 => {}
+            _ => return Err(format!("가격 상한은 오름차순이어야 합니다: {:?}", step)),
+        }
+    }
+    Ok(())
+}
+
+/// 현재 가격에 적용되는 최소 증가 금액 (구간은 `validate_ladder`로 검증된 오름차순)
+/// 구간에 해당하지 않으면 마지막 구간의 증가 금액 적용, 구간이 없으면 1
+pub fn min_increment(ladder: &[IncrementStep], current_price: i64) -> i64 {
+    ladder
+        .iter()
+        .find(|step| step.below.is_none_or(|below| current_price < below))
+        .or(ladder.last())
+        .map_or(1, |step| step.increment)
+}
+
+// endregion: --- Increment Ladder
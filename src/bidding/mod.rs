@@ -1,2 +1,4 @@
 pub mod commands;
+pub mod increment;
 pub mod model;
+pub mod retraction;
@@ -1,8 +1,10 @@
+use super::increment::IncrementStep;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
 
 // 상품 모델
-#[derive(Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Item {
     pub id: i64,
     pub title: String,
@@ -10,19 +12,76 @@ pub struct Item {
     pub starting_price: i64,
     pub current_price: i64,
     pub buy_now_price: i64,
+    // 최저 낙찰가(비공개)
+    #[serde(skip_serializing)]
+    pub reserve_price: Option<i64>,
+    // 최저 낙찰가 충족 여부 (조회 시 계산)
+    #[sqlx(default)]
+    pub reserve_met: bool,
+    // 상품별 입찰 단위 (NULL: 전역 설정 사용)
+    pub bid_increments: Option<Json<Vec<IncrementStep>>>,
     pub start_time: DateTime<Utc>,
     pub end_time: DateTime<Utc>,
+    pub soft_close_window_secs: i64,
+    pub soft_close_extension_secs: i64,
+    // 경매 방식 (ENGLISH: 오름차순, DUTCH: 가격 하락식, SEALED_FIRST_PRICE/SEALED_SECOND_PRICE: 비공개 입찰)
+    #[serde(default = "default_auction_type")]
+    pub auction_type: String,
+    // 가격 하락 금액 및 주기(초) (DUTCH)
+    #[serde(default)]
+    pub price_drop_amount: i64,
+    #[serde(default)]
+    pub price_drop_interval_secs: i64,
     pub seller: String,
     pub status: String,
+    // 낙찰자 (경매 종료 또는 즉시 구매 시 결정)
+    pub winner_id: Option<i64>,
     pub created_at: DateTime<Utc>,
 }
 
+/// 기본 경매 방식 (오름차순 경매)
+pub fn default_auction_type() -> String {
+    "ENGLISH".to_string()
+}
+
 // 입찰 모델
-#[derive(Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Bid {
     pub id: i64,
     pub item_id: i64,
     pub bidder_id: i64,
     pub bid_amount: i64,
     pub bid_time: DateTime<Utc>,
+    pub is_auto: bool,
+    // 철회 시각 (철회된 입찰은 최고 입찰가 계산에서 제외)
+    #[sqlx(default)]
+    pub retracted_at: Option<DateTime<Utc>>,
+}
+
+// 금액을 숨긴 입찰 모델 (비공개 입찰 경매 종료 전 조회)
+#[derive(Clone, Serialize)]
+pub struct SealedBid {
+    pub id: i64,
+    pub item_id: i64,
+    pub bidder_id: i64,
+    pub bid_time: DateTime<Utc>,
+}
+
+impl From<Bid> for SealedBid {
+    fn from(bid: Bid) -> Self {
+        Self {
+            id: bid.id,
+            item_id: bid.item_id,
+            bidder_id: bid.bidder_id,
+            bid_time: bid.bid_time,
+        }
+    }
+}
+
+// 자동 입찰(최대 입찰가) 모델
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxyBid {
+    pub bidder_id: i64,
+    pub max_amount: i64,
+    pub version: i64,
 }
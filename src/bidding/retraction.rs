@@ -0,0 +1,72 @@
+/// 입찰 철회 정책
+/// 잘못 입력한 입찰(e.g., 0을 하나 더 입력)을 바로잡을 수 있도록 제한된 조건에서만 철회 허용
+/// 1. 입찰 후 `BID_RETRACTION_WINDOW_SECS`(기본 600초) 이내
+/// 2. 경매 종료 `BID_RETRACTION_CUTOFF_SECS`(기본 3600초) 전까지 (마감 직전 가격 조작 방지)
+///
+/// 철회 조건은 입찰자 보호 규칙이므로 상품별로 지정하지 않고 서비스 전체에 같은 정책 적용
+/// (판매자가 상품마다 철회 조건을 바꾸어 입찰자에게 불리하게 운영하지 않도록)
+// region:    --- Imports
+use crate::error::AuctionError;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::OnceLock;
+
+// endregion: --- Imports
+
+// region:    --- Retraction Policy
+/// 기본 철회 가능 시간 (입찰 후, 초)
+const DEFAULT_WINDOW_SECS: i64 = 600;
+
+/// 기본 철회 마감 시간 (경매 종료 전, 초)
+const DEFAULT_CUTOFF_SECS: i64 = 3600;
+
+static POLICY: OnceLock<RetractionPolicy> = OnceLock::new();
+
+/// 입찰 철회 정책
+#[derive(Debug, Clone, Copy)]
+pub struct RetractionPolicy {
+    /// 입찰 후 철회 가능 시간 (초)
+    pub window_secs: i64,
+    /// 경매 종료 전 철회 마감 시간 (초)
+    pub cutoff_secs: i64,
+}
+
+impl RetractionPolicy {
+    /// 전역 정책 조회 (`BID_RETRACTION_WINDOW_SECS`, `BID_RETRACTION_CUTOFF_SECS` 환경 변수)
+    pub fn global() -> Self {
+        *POLICY.get_or_init(|| Self {
+            window_secs: env_secs("BID_RETRACTION_WINDOW_SECS", DEFAULT_WINDOW_SECS),
+            cutoff_secs: env_secs("BID_RETRACTION_CUTOFF_SECS", DEFAULT_CUTOFF_SECS),
+        })
+    }
+
+    /// 철회 가능 여부 확인
+    pub fn check(
+        &self,
+        bid_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<(), AuctionError> {
+        if now - bid_time > Duration::seconds(self.window_secs) {
+            return Err(AuctionError::RetractionNotAllowed(
+                "retraction.window_expired",
+            ));
+        }
+        if end_time - now < Duration::seconds(self.cutoff_secs) {
+            return Err(AuctionError::RetractionNotAllowed(
+                "retraction.closing_soon",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 초 단위 설정 조회 (없거나 음수면 기본값)
+fn env_secs(name: &str, default: i64) -> i64 {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse::<i64>().ok())
+        .filter(|secs| *secs >= 0)
+        .unwrap_or(default)
+}
+
+// endregion: --- Retraction Policy
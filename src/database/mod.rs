@@ -2,11 +2,19 @@ use sqlx::postgres::{PgPool, PgPoolOptions};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
+use tracing::{info, warn};
 
 pub struct DatabaseManager {
     pub pool: Arc<PgPool>,
 }
 
+/// 개발용 데이터베이스 초기화 여부 (`RESET_DEV_DATABASE` 환경 변수, 기본 false)
+fn reset_dev_database() -> bool {
+    std::env::var("RESET_DEV_DATABASE")
+        .map(|value| matches!(value.as_str(), "true" | "1"))
+        .unwrap_or(false)
+}
+
 impl DatabaseManager {
     /// 데이터베이스 매니저 생성
     pub async fn new() -> Self {
@@ -21,11 +29,6 @@ impl DatabaseManager {
         }
     }
 
-    /// 데이터베이스 풀 가져오기
-    pub fn get_pool(&self) -> Arc<PgPool> {
-        Arc::clone(&self.pool)
-    }
-
     /// 트랜잭션 실행
     pub async fn transaction<F, R, E>(&self, f: F) -> Result<R, E>
     where
@@ -49,26 +52,40 @@ impl DatabaseManager {
     }
 
     /// 데이터베이스 초기화
+    /// 1. `RESET_DEV_DATABASE=true`이면 개발용 데이터베이스 초기화 (모든 데이터 삭제)
+    /// 2. 적용되지 않은 마이그레이션 적용
+    /// 3. 초기화한 경우 개발용 샘플 데이터 삽입
     pub async fn initialize_database(&self) -> Result<(), sqlx::Error> {
-        // 00-recreate-db.sql 실행
-        let recreate_db_sql = include_str!("../sql/00-recreate-db.sql");
-        self.execute_multi_query(recreate_db_sql).await?;
+        let reset = reset_dev_database();
+        if reset {
+            warn!("{:<12} --> 개발용 데이터베이스 초기화", "Database");
+            sqlx::raw_sql(include_str!("../sql/reset-dev-db.sql"))
+                .execute(&*self.pool)
+                .await?;
+        }
 
-        // 01-create-schema.sql 실행
-        let create_schema_sql = include_str!("../sql/01-create-schema.sql");
-        self.execute_multi_query(create_schema_sql).await?;
+        self.run_migrations().await?;
 
+        if reset {
+            sqlx::raw_sql(include_str!("../sql/seed-dev-data.sql"))
+                .execute(&*self.pool)
+                .await?;
+            info!("{:<12} --> 개발용 샘플 데이터 삽입", "Database");
+        }
         Ok(())
     }
 
-    /// 여러 쿼리 실행
-    async fn execute_multi_query(&self, sql: &str) -> Result<(), sqlx::Error> {
-        for query in sql.split(';') {
-            let query = query.trim();
-            if !query.is_empty() {
-                sqlx::query(query).execute(&*self.pool).await?;
-            }
-        }
+    /// 마이그레이션 적용
+    /// `migrations` 디렉터리의 번호순 마이그레이션 중 적용되지 않은 것만 적용하고 `_sqlx_migrations`에 이력 기록
+    /// 이미 적용된 마이그레이션 파일이 변경되었으면(체크섬 불일치) 오류 반환
+    pub async fn run_migrations(&self) -> Result<(), sqlx::Error> {
+        let migrator = sqlx::migrate!("./migrations");
+        migrator.run(&*self.pool).await?;
+        info!(
+            "{:<12} --> 마이그레이션 적용 완료: 최신 버전 {}",
+            "Database",
+            migrator.iter().map(|m| m.version).max().unwrap_or(0)
+        );
         Ok(())
     }
 
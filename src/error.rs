@@ -0,0 +1,307 @@
+/// 서비스 공통 오류
+/// 명령, 이벤트 저장소, 조회 모델 저장소, HTTP 핸들러가 같은 오류 타입을 사용하며,
+/// HTTP 응답은 RFC 7807(Problem Details) 형식의 `application/problem+json` 본문으로 변환
+/// `code`는 클라이언트가 분기에 사용하는 고정 값이므로 변경하지 않는다.
+/// 제목과 상세 메시지는 `code`를 키로 메시지 카탈로그(`i18n`)에서 조회하며, 요청 언어(`Locale`)로 응답
+// region:    --- Imports
+use crate::i18n::Locale;
+use axum::http::{header, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::{json, Map, Value};
+use std::fmt;
+use tracing::error;
+
+// endregion: --- Imports
+
+// region:    --- Auction Error
+/// Problem Details 응답 Content-Type
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+/// 서비스 공통 오류
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuctionError {
+    /// 리소스 없음 (`resource`는 메시지 카탈로그의 리소스 이름 키, e.g., `resource.item`)
+    NotFound {
+        resource: &'static str,
+        id: Option<i64>,
+    },
+    /// 경매 시작 전
+    NotStarted,
+    /// 경매 종료
+    AlreadyEnded,
+    /// 명령을 처리할 수 없는 경매 상태 (현재 상태 값)
+    InvalidStatus(String),
+    /// 입찰 금액이 현재 가격 이하
+    LowBid { bid_amount: i64 },
+    /// 입찰 금액이 최소 입찰 단위 미만
+    BidIncrementTooSmall {
+        bid_amount: i64,
+        next_acceptable_amount: i64,
+    },
+    /// 최대 입찰가가 입찰 금액보다 낮음
+    InvalidMaxBid { bid_amount: i64, max_bid: i64 },
+    /// 상품 등록 정보가 규칙에 맞지 않음 (사유는 메시지 카탈로그 키, e.g., `item.end_before_start`)
+    InvalidItem(&'static str),
+    /// 경매 취소 사유 누락
+    CancelReasonRequired,
+    /// 상품 판매자가 아닌 요청자
+    NotItemSeller,
+    /// 관리자 토큰이 없거나 일치하지 않는 관리자 요청
+    AdminRequired,
+    /// 자동 입찰을 사용할 수 없는 경매 방식
+    ProxyBidNotSupported,
+    /// 즉시 구매를 사용할 수 없는 경매 방식
+    BuyNowNotSupported,
+    /// 입찰 철회 정책에 맞지 않음 (사유는 메시지 카탈로그 키, e.g., `retraction.window_expired`)
+    RetractionNotAllowed(&'static str),
+    /// 낙관적 동시성 제어 버전 충돌
+    VersionConflict,
+    /// 버전 충돌 재시도 횟수 초과
+    MaxRetriesExceeded,
+    /// 다른 요청에 사용된 멱등성 키
+    IdempotencyKeyReused,
+    /// 같은 멱등성 키로 처리 중인 요청 존재
+    IdempotencyKeyInProgress,
+    /// 이미 재처리된 데드레터
+    AlreadyReplayed,
+    /// 데드레터 재처리 실패 (실패 사유)
+    ReplayFailed(String),
+    /// 데이터베이스 오류
+    Database(String),
+    /// 그 외 내부 오류
+    Internal(String),
+}
+
+impl AuctionError {
+    /// 리소스 없음 오류 생성
+    pub fn not_found(resource: &'static str, id: i64) -> Self {
+        Self::NotFound {
+            resource,
+            id: Some(id),
+        }
+    }
+
+    /// 리소스 조회 오류 변환 (조회 결과가 없으면 리소스 없음 오류)
+    pub fn from_query(e: sqlx::Error, resource: &'static str, id: i64) -> Self {
+        match e {
+            sqlx::Error::RowNotFound => Self::not_found(resource, id),
+            e => Self::from(e),
+        }
+    }
+
+    /// 기계 판독용 오류 코드 (메시지 카탈로그 키)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotFound { .. } => "NOT_FOUND",
+            Self::NotStarted => "NOT_STARTED",
+            Self::AlreadyEnded => "ALREADY_ENDED",
+            Self::InvalidStatus(_) => "INVALID_STATUS",
+            Self::LowBid { .. } => "LOW_BID",
+            Self::BidIncrementTooSmall { .. } => "BID_INCREMENT_TOO_SMALL",
+            Self::InvalidMaxBid { .. } => "INVALID_MAX_BID",
+            Self::InvalidItem(_) => "INVALID_ITEM",
+            Self::CancelReasonRequired => "CANCEL_REASON_REQUIRED",
+            Self::NotItemSeller => "NOT_ITEM_SELLER",
+            Self::AdminRequired => "ADMIN_REQUIRED",
+            Self::ProxyBidNotSupported => "PROXY_BID_NOT_SUPPORTED",
+            Self::BuyNowNotSupported => "BUY_NOW_NOT_SUPPORTED",
+            Self::RetractionNotAllowed(_) => "RETRACTION_NOT_ALLOWED",
+            Self::VersionConflict => "VERSION_CONFLICT",
+            Self::MaxRetriesExceeded => "MAX_RETRIES_EXCEEDED",
+            Self::IdempotencyKeyReused => "IDEMPOTENCY_KEY_REUSED",
+            Self::IdempotencyKeyInProgress => "IDEMPOTENCY_KEY_IN_PROGRESS",
+            Self::AlreadyReplayed => "ALREADY_REPLAYED",
+            Self::ReplayFailed(_) => "REPLAY_FAILED",
+            Self::Database(_) => "DATABASE_ERROR",
+            Self::Internal(_) => "INTERNAL_ERROR",
+        }
+    }
+
+    /// HTTP 상태 코드
+    /// 1. 403: 상품 판매자 또는 관리자만 가능한 요청
+    /// 2. 404: 리소스 없음
+    /// 3. 409: 현재 경매 상태나 동시 요청과 충돌 (같은 요청을 나중에 다시 보낼 수 있음)
+    /// 4. 422: 요청 값 자체가 규칙에 맞지 않음
+    /// 5. 500: 서버 오류
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::NotItemSeller | Self::AdminRequired => StatusCode::FORBIDDEN,
+            Self::NotFound { .. } => StatusCode::NOT_FOUND,
+            Self::NotStarted
+            | Self::AlreadyEnded
+            | Self::InvalidStatus(_)
+            | Self::RetractionNotAllowed(_)
+            | Self::VersionConflict
+            | Self::MaxRetriesExceeded
+            | Self::IdempotencyKeyInProgress
+            | Self::AlreadyReplayed => StatusCode::CONFLICT,
+            Self::LowBid { .. }
+            | Self::BidIncrementTooSmall { .. }
+            | Self::InvalidMaxBid { .. }
+            | Self::InvalidItem(_)
+            | Self::CancelReasonRequired
+            | Self::ProxyBidNotSupported
+            | Self::BuyNowNotSupported
+            | Self::IdempotencyKeyReused
+            | Self::ReplayFailed(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::Database(_) | Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// 오류 유형 제목 (같은 코드는 항상 같은 제목)
+    pub fn title(&self, locale: Locale) -> &'static str {
+        locale.message(self.code())
+    }
+
+    /// 오류 상세 메시지
+    pub fn detail(&self, locale: Locale) -> String {
+        match self {
+            Self::NotFound {
+                resource,
+                id: Some(id),
+            } => locale.format(
+                "NOT_FOUND.detail",
+                &[
+                    ("resource", locale.message(resource).to_string()),
+                    ("id", id.to_string()),
+                ],
+            ),
+            Self::InvalidStatus(status) => {
+                locale.format("INVALID_STATUS.detail", &[("status", status.clone())])
+            }
+            Self::LowBid { bid_amount } => {
+                locale.format("LOW_BID.detail", &[("bid_amount", bid_amount.to_string())])
+            }
+            Self::BidIncrementTooSmall {
+                bid_amount,
+                next_acceptable_amount,
+            } => locale.format(
+                "BID_INCREMENT_TOO_SMALL.detail",
+                &[
+                    ("bid_amount", bid_amount.to_string()),
+                    ("next_acceptable_amount", next_acceptable_amount.to_string()),
+                ],
+            ),
+            Self::InvalidMaxBid {
+                bid_amount,
+                max_bid,
+            } => locale.format(
+                "INVALID_MAX_BID.detail",
+                &[
+                    ("bid_amount", bid_amount.to_string()),
+                    ("max_bid", max_bid.to_string()),
+                ],
+            ),
+            Self::InvalidItem(reason) => locale.format(
+                "INVALID_ITEM.detail",
+                &[("reason", locale.message(reason).to_string())],
+            ),
+            Self::RetractionNotAllowed(reason) => locale.format(
+                "RETRACTION_NOT_ALLOWED.detail",
+                &[("reason", locale.message(reason).to_string())],
+            ),
+            Self::ReplayFailed(reason) => {
+                locale.format("REPLAY_FAILED.detail", &[("reason", reason.clone())])
+            }
+            // 데이터베이스/내부 오류 원문은 번역하지 않음
+            Self::Database(detail) | Self::Internal(detail) => detail.clone(),
+            _ => self.title(locale).to_string(),
+        }
+    }
+
+    /// 오류 코드별 추가 필드 (Problem Details 확장 멤버)
+    fn extensions(&self) -> Map<String, Value> {
+        let extensions = match self {
+            Self::LowBid { bid_amount } => json!({"bid_amount": bid_amount}),
+            Self::BidIncrementTooSmall {
+                bid_amount,
+                next_acceptable_amount,
+            } => json!({
+                "bid_amount": bid_amount,
+                "next_acceptable_amount": next_acceptable_amount,
+            }),
+            Self::InvalidMaxBid {
+                bid_amount,
+                max_bid,
+            } => json!({"bid_amount": bid_amount, "max_bid": max_bid}),
+            Self::InvalidItem(reason) | Self::RetractionNotAllowed(reason) => {
+                json!({"reason": reason})
+            }
+            _ => json!({}),
+        };
+        match extensions {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        }
+    }
+
+    /// RFC 7807 Problem Details 본문
+    pub fn to_problem(&self, locale: Locale) -> Value {
+        let mut problem = Map::new();
+        problem.insert(
+            "type".to_string(),
+            json!(format!(
+                "/problems/{}",
+                self.code().to_lowercase().replace('_', "-")
+            )),
+        );
+        problem.insert("title".to_string(), json!(self.title(locale)));
+        problem.insert("status".to_string(), json!(self.status().as_u16()));
+        problem.insert("detail".to_string(), json!(self.detail(locale)));
+        problem.insert("code".to_string(), json!(self.code()));
+        problem.extend(self.extensions());
+        Value::Object(problem)
+    }
+
+    /// 요청 언어로 Problem Details 응답 생성
+    pub fn to_response(&self, locale: Locale) -> Response {
+        let status = self.status();
+        if status.is_server_error() {
+            error!("{:<12} --> {}: {}", "Error", self.code(), self);
+        }
+        let mut response = (status, Json(self.to_problem(locale))).into_response();
+        let headers = response.headers_mut();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE),
+        );
+        headers.insert(header::CONTENT_LANGUAGE, locale.content_language());
+        response
+    }
+}
+
+impl fmt::Display for AuctionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.detail(Locale::default()))
+    }
+}
+
+impl std::error::Error for AuctionError {}
+
+impl From<sqlx::Error> for AuctionError {
+    fn from(e: sqlx::Error) -> Self {
+        match e {
+            sqlx::Error::RowNotFound => Self::NotFound {
+                resource: "resource.unknown",
+                id: None,
+            },
+            e => Self::Database(e.to_string()),
+        }
+    }
+}
+
+impl From<serde_json::Error> for AuctionError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Internal(e.to_string())
+    }
+}
+
+/// 요청 언어를 알 수 없으면 기본 언어(한국어)로 응답
+impl IntoResponse for AuctionError {
+    fn into_response(self) -> Response {
+        self.to_response(Locale::default())
+    }
+}
+
+// endregion: --- Auction Error
@@ -0,0 +1,150 @@
+/// 인메모리 이벤트 저장소
+/// 데이터베이스 없이 명령 처리 로직을 테스트할 때 사용
+/// PostgreSQL 구현과 동일하게 (aggregate_id, version)이 중복되면 전체 이벤트를 저장하지 않고 `AuctionError::VersionConflict` 반환
+/// 발행은 메시지 브로커 대신 저장된 이벤트 목록으로 확인
+// region:    --- Imports
+use super::{Event, EventStore, Snapshot};
+use crate::error::AuctionError;
+use async_trait::async_trait;
+use std::sync::Mutex;
+
+// endregion: --- Imports
+
+// region:    --- In-Memory Event Store
+/// 저장된 이벤트와 스냅샷
+#[derive(Default)]
+struct Store {
+    events: Vec<Event>,
+    snapshots: Vec<Snapshot>,
+    /// 마지막으로 발급한 애그리거트 id
+    last_aggregate_id: i64,
+}
+
+/// 인메모리 이벤트 저장소
+#[derive(Default)]
+pub struct InMemoryEventStore {
+    store: Mutex<Store>,
+}
+
+impl InMemoryEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 저장된 전체 이벤트 조회 (저장 순)
+    pub fn events(&self) -> Vec<Event> {
+        self.store
+            .lock()
+            .expect("이벤트 저장소 잠금 실패")
+            .events
+            .clone()
+    }
+}
+
+#[async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append_and_publish_event(&self, event: Event) -> Result<(), AuctionError> {
+        self.append_and_publish_events(vec![event]).await
+    }
+
+    async fn append_and_publish_events(&self, events: Vec<Event>) -> Result<(), AuctionError> {
+        let mut store = self
+            .store
+            .lock()
+            .map_err(|e| AuctionError::Internal(e.to_string()))?;
+
+        // 하나라도 버전이 충돌하면 전체 저장하지 않음
+        let conflict = events.iter().enumerate().any(|(i, event)| {
+            store.events.iter().chain(&events[..i]).any(|saved| {
+                saved.aggregate_id == event.aggregate_id && saved.version == event.version
+            })
+        });
+        if conflict {
+            return Err(AuctionError::VersionConflict);
+        }
+
+        for mut event in events {
+            event.id = store.events.len() as i64 + 1;
+            event.position = event.id;
+            store.events.push(event);
+        }
+        Ok(())
+    }
+
+    async fn load_events(
+        &self,
+        aggregate_id: i64,
+        after_version: i64,
+    ) -> Result<Vec<Event>, AuctionError> {
+        let store = self
+            .store
+            .lock()
+            .map_err(|e| AuctionError::Internal(e.to_string()))?;
+        let mut events: Vec<Event> = store
+            .events
+            .iter()
+            .filter(|event| event.aggregate_id == aggregate_id && event.version > after_version)
+            .cloned()
+            .collect();
+        events.sort_by_key(|event| event.version);
+        Ok(events)
+    }
+
+    async fn load_snapshot(
+        &self,
+        aggregate_id: i64,
+        schema_version: i32,
+    ) -> Result<Option<Snapshot>, AuctionError> {
+        let store = self
+            .store
+            .lock()
+            .map_err(|e| AuctionError::Internal(e.to_string()))?;
+        Ok(store
+            .snapshots
+            .iter()
+            .filter(|snapshot| {
+                snapshot.aggregate_id == aggregate_id && snapshot.schema_version == schema_version
+            })
+            .max_by_key(|snapshot| snapshot.version)
+            .cloned())
+    }
+
+    async fn save_snapshot(&self, snapshot: Snapshot) -> Result<(), AuctionError> {
+        let mut store = self
+            .store
+            .lock()
+            .map_err(|e| AuctionError::Internal(e.to_string()))?;
+        store.snapshots.retain(|saved| {
+            saved.aggregate_id != snapshot.aggregate_id || saved.version != snapshot.version
+        });
+        store.snapshots.push(snapshot);
+        Ok(())
+    }
+
+    async fn delete_snapshots(&self) -> Result<u64, AuctionError> {
+        let mut store = self
+            .store
+            .lock()
+            .map_err(|e| AuctionError::Internal(e.to_string()))?;
+        let deleted = store.snapshots.len() as u64;
+        store.snapshots.clear();
+        Ok(deleted)
+    }
+
+    /// 저장된 이벤트의 애그리거트 id와 이전에 발급한 id보다 큰 값 발급
+    async fn next_aggregate_id(&self) -> Result<i64, AuctionError> {
+        let mut store = self
+            .store
+            .lock()
+            .map_err(|e| AuctionError::Internal(e.to_string()))?;
+        let max_aggregate_id = store
+            .events
+            .iter()
+            .map(|event| event.aggregate_id)
+            .fold(store.last_aggregate_id, i64::max);
+        store.last_aggregate_id = max_aggregate_id + 1;
+        Ok(store.last_aggregate_id)
+    }
+}
+
+// endregion: --- In-Memory Event Store
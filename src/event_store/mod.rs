@@ -1,13 +1,21 @@
 // region:    --- Imports
 use crate::auction::events::AuctionEvent;
 use crate::database::DatabaseManager;
-use crate::message_broker::{KafkaConsumer, KafkaProducer};
+use crate::error::AuctionError;
+use crate::message_broker::{self, DeadLetter, MessageBroker};
+use crate::query::handlers::get_dead_letter;
+use crate::realtime::AuctionUpdates;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use sqlx::FromRow;
+use sqlx::types::Json;
+use sqlx::{FromRow, Postgres, Transaction};
 use std::sync::Arc;
 use tracing::{error, info, warn};
 
+pub mod memory;
+
+pub use memory::InMemoryEventStore;
+
 // endregion: --- Imports
 
 // region:    --- Event Model
@@ -20,6 +28,38 @@ pub struct Event {
     pub data: serde_json::Value,
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub version: i64,
+    /// 이벤트를 발생시킨 명령의 멱등성 키
+    #[serde(default)]
+    #[sqlx(default)]
+    pub idempotency_key: Option<String>,
+    /// 이벤트 위치 (저장 시 발급, SSE 재연결 시 이어받기 기준)
+    #[serde(default)]
+    #[sqlx(default)]
+    pub position: i64,
+}
+
+/// 애그리거트 상태 스냅샷 모델
+#[derive(Debug, Serialize, Deserialize, FromRow, Clone)]
+pub struct Snapshot {
+    pub aggregate_id: i64,
+    pub version: i64,
+    pub schema_version: i32,
+    pub data: serde_json::Value,
+}
+
+/// 데드레터 모델 (조회 모델에 반영하지 못한 메시지)
+#[derive(Debug, Serialize, Deserialize, FromRow, Clone)]
+pub struct DeadLetterRecord {
+    pub id: i64,
+    pub topic: String,
+    pub partition_id: i32,
+    pub message_offset: i64,
+    pub message_key: Option<String>,
+    pub payload: String,
+    pub error: String,
+    pub retry_count: i32,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub replayed_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 // endregion: --- Event Model
 
@@ -27,56 +67,169 @@ pub struct Event {
 /// 이벤트 저장소 트레이트
 #[async_trait]
 pub trait EventStore {
-    async fn append_and_publish_event(&self, event: Event) -> Result<(), String>;
+    /// 이벤트 저장 후 발행
+    async fn append_and_publish_event(&self, event: Event) -> Result<(), AuctionError>;
+
+    /// 여러 이벤트를 하나의 트랜잭션으로 저장 후 발행
+    /// 발행할 메시지는 같은 트랜잭션으로 outbox에 기록하며, 실제 발행은 아웃박스 릴레이가 수행
+    async fn append_and_publish_events(&self, events: Vec<Event>) -> Result<(), AuctionError>;
+
+    /// 애그리거트의 이벤트 조회 (after_version 이후, 버전 순)
+    async fn load_events(
+        &self,
+        aggregate_id: i64,
+        after_version: i64,
+    ) -> Result<Vec<Event>, AuctionError>;
+
+    /// 애그리거트의 최신 스냅샷 조회 (schema_version이 일치하는 스냅샷만)
+    async fn load_snapshot(
+        &self,
+        aggregate_id: i64,
+        schema_version: i32,
+    ) -> Result<Option<Snapshot>, AuctionError>;
+
+    /// 스냅샷 저장
+    async fn save_snapshot(&self, snapshot: Snapshot) -> Result<(), AuctionError>;
+
+    /// 전체 스냅샷 삭제 (애그리거트 로직 변경 시 무효화)
+    async fn delete_snapshots(&self) -> Result<u64, AuctionError>;
+
+    /// 새 애그리거트(상품) id 발급
+    async fn next_aggregate_id(&self) -> Result<i64, AuctionError>;
 }
 
 /// 이벤트 저장소 구현체
 pub struct PostgresEventStore {
     db_manager: Arc<DatabaseManager>,
-    kafka_producer: Arc<KafkaProducer>,
 }
 
 /// 이벤트 저장소 구현체 메서드 구현
 #[async_trait]
 impl EventStore for PostgresEventStore {
-    async fn append_and_publish_event(&self, event: Event) -> Result<(), String> {
-        let event_id = sqlx::query_scalar::<_, i64>(
-            "INSERT INTO events (aggregate_id, event_type, data, timestamp, version)
-            VALUES ($1, $2, $3, $4, $5)
-            ON CONFLICT (aggregate_id, version) DO NOTHING
-            RETURNING id",
+    async fn append_and_publish_event(&self, event: Event) -> Result<(), AuctionError> {
+        self.append_and_publish_events(vec![event]).await
+    }
+
+    async fn append_and_publish_events(&self, events: Vec<Event>) -> Result<(), AuctionError> {
+        let mut tx = self.db_manager.pool().begin().await?;
+
+        // 이벤트 및 outbox 저장 (하나라도 버전이 충돌하면 전체 롤백)
+        // 메시지 키는 애그리거트 id로 지정하여 같은 상품의 이벤트는 같은 파티션에서 순서대로 소비
+        for mut event in events {
+            let saved = sqlx::query_as::<_, (i64, i64)>(
+                "INSERT INTO events (aggregate_id, event_type, data, timestamp, version, idempotency_key)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (aggregate_id, version) DO NOTHING
+                RETURNING id, position",
+            )
+            .bind(event.aggregate_id)
+            .bind(&event.event_type)
+            .bind(&event.data)
+            .bind(event.timestamp)
+            .bind(event.version)
+            .bind(&event.idempotency_key)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+            (event.id, event.position) = match saved {
+                Some(saved) => saved,
+                None => {
+                    tx.rollback().await?;
+                    return Err(AuctionError::VersionConflict);
+                }
+            };
+
+            sqlx::query(
+                "INSERT INTO outbox (event_id, topic, message_key, payload)
+                VALUES ($1, $2, $3, $4)",
+            )
+            .bind(event.id)
+            .bind("events")
+            .bind(event.aggregate_id.to_string())
+            .bind(serde_json::to_value(&event)?)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn load_events(
+        &self,
+        aggregate_id: i64,
+        after_version: i64,
+    ) -> Result<Vec<Event>, AuctionError> {
+        sqlx::query_as::<_, Event>(
+            "SELECT id, aggregate_id, event_type, data, timestamp, version, idempotency_key, position
+            FROM events
+            WHERE aggregate_id = $1 AND version > $2
+            ORDER BY version",
+        )
+        .bind(aggregate_id)
+        .bind(after_version)
+        .fetch_all(self.db_manager.pool())
+        .await
+        .map_err(AuctionError::from)
+    }
+
+    async fn load_snapshot(
+        &self,
+        aggregate_id: i64,
+        schema_version: i32,
+    ) -> Result<Option<Snapshot>, AuctionError> {
+        sqlx::query_as::<_, Snapshot>(
+            "SELECT aggregate_id, version, schema_version, data
+            FROM snapshots
+            WHERE aggregate_id = $1 AND schema_version = $2
+            ORDER BY version DESC
+            LIMIT 1",
         )
-        .bind(event.aggregate_id)
-        .bind(&event.event_type)
-        .bind(&event.data)
-        .bind(event.timestamp)
-        .bind(event.version)
+        .bind(aggregate_id)
+        .bind(schema_version)
         .fetch_optional(self.db_manager.pool())
         .await
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| "버전 충돌".to_string())?;
+        .map_err(AuctionError::from)
+    }
 
-        // 이벤트를 카프카에 발행
-        self.kafka_producer
-            .send_message(
-                "events",
-                &event_id.to_string(),
-                &serde_json::to_string(&event).unwrap(),
-            )
+    async fn save_snapshot(&self, snapshot: Snapshot) -> Result<(), AuctionError> {
+        sqlx::query(
+            "INSERT INTO snapshots (aggregate_id, version, schema_version, data)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (aggregate_id, version) DO UPDATE
+            SET schema_version = EXCLUDED.schema_version, data = EXCLUDED.data,
+                created_at = CURRENT_TIMESTAMP",
+        )
+        .bind(snapshot.aggregate_id)
+        .bind(snapshot.version)
+        .bind(snapshot.schema_version)
+        .bind(&snapshot.data)
+        .execute(self.db_manager.pool())
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_snapshots(&self) -> Result<u64, AuctionError> {
+        sqlx::query("DELETE FROM snapshots")
+            .execute(self.db_manager.pool())
             .await
-            .map_err(|e| e.to_string())?;
+            .map(|result| result.rows_affected())
+            .map_err(AuctionError::from)
+    }
 
-        Ok(())
+    /// 조회 모델의 상품 id와 겹치지 않도록 상품 id 시퀀스 사용
+    async fn next_aggregate_id(&self) -> Result<i64, AuctionError> {
+        sqlx::query_scalar::<_, i64>("SELECT nextval('items_id_seq')")
+            .fetch_one(self.db_manager.pool())
+            .await
+            .map_err(AuctionError::from)
     }
 }
 
 /// 이벤트 저장소 생성
 impl PostgresEventStore {
-    pub fn new(db_manager: Arc<DatabaseManager>, kafka_producer: Arc<KafkaProducer>) -> Self {
-        Self {
-            db_manager,
-            kafka_producer,
-        }
+    pub fn new(db_manager: Arc<DatabaseManager>) -> Self {
+        Self { db_manager }
     }
 }
 
@@ -86,48 +239,246 @@ impl PostgresEventStore {
 /// 이벤트 소싱 구현체
 pub struct EventConsumer {
     db_manager: Arc<DatabaseManager>,
-    kafka_consumer: Arc<KafkaConsumer>,
+    message_broker: Arc<dyn MessageBroker>,
+    updates: Arc<AuctionUpdates>,
 }
 
 /// 이벤트 소싱 구현체 메서드 구현
 impl EventConsumer {
     /// 이벤트 소싱 생성
-    pub fn new(db_manager: Arc<DatabaseManager>, kafka_consumer: Arc<KafkaConsumer>) -> Self {
+    /// 조회 모델에 반영한 이벤트는 `updates`로 실시간 구독자에게 알림
+    pub fn new(
+        db_manager: Arc<DatabaseManager>,
+        message_broker: Arc<dyn MessageBroker>,
+        updates: Arc<AuctionUpdates>,
+    ) -> Self {
         EventConsumer {
             db_manager,
-            kafka_consumer,
+            message_broker,
+            updates,
         }
     }
 
     /// 이벤트 소싱 시작
+    /// 재시도 후에도 처리에 실패한 이벤트는 데드레터 토픽으로 보내고 dead_letters 테이블에 기록
     pub async fn start(&self) {
         let db_manager = Arc::clone(&self.db_manager);
-        if let Err(e) = self
-            .kafka_consumer
-            .consume_events("events", move |event| {
+        let updates = Arc::clone(&self.updates);
+        let dead_letter_db_manager = Arc::clone(&self.db_manager);
+        if let Err(e) = message_broker::consume_events(
+            &*self.message_broker,
+            "events",
+            move |event| {
                 let db_manager = Arc::clone(&db_manager);
+                let updates = Arc::clone(&updates);
                 // Return a boxed future
                 Box::pin(async move {
-                    if let Err(e) = Self::process_event(&db_manager, event).await {
-                        error!("{:<12} --> 이벤트 처리 오류: {:?}", "EventConsume", e);
+                    match Self::process_event(&db_manager, event).await {
+                        Ok(applied) => {
+                            updates.publish(applied);
+                            Ok(())
+                        }
+                        Err(e) => {
+                            error!("{:<12} --> 이벤트 처리 오류: {:?}", "EventConsume", e);
+                            Err(e)
+                        }
                     }
-                    Ok(())
                 })
-            })
-            .await
+            },
+            move |dead_letter| {
+                let db_manager = Arc::clone(&dead_letter_db_manager);
+                Box::pin(async move {
+                    if let Err(e) = Self::record_dead_letter(&db_manager, dead_letter).await {
+                        error!("{:<12} --> 데드레터 기록 오류: {:?}", "EventConsume", e);
+                    }
+                })
+            },
+        )
+        .await
         {
             error!("{:<12} --> 이벤트 소비 오류: {:?}", "EventConsume", e);
         }
     }
 
     /// 이벤트 처리
+    /// 애그리거트별로 마지막으로 반영한 이벤트 버전을 기록하여, 재전달된 이벤트는 다시 반영하지 않음
+    /// 버전이 건너뛰어진 경우 누락된 이벤트를 이벤트 저장소(PostgreSQL)에서 조회하여 먼저 반영
+    /// 조회 모델 반영과 오프셋 기록은 하나의 트랜잭션으로 처리
+    /// 커밋 후 새로 반영한 이벤트 목록 반환 (이미 반영된 이벤트면 빈 목록)
     async fn process_event(
         db_manager: &DatabaseManager,
         event: Event,
+    ) -> Result<Vec<Event>, Box<dyn std::error::Error>> {
+        let mut tx = db_manager.pool().begin().await?;
+
+        // 애그리거트별 반영 오프셋 잠금 (같은 애그리거트의 이벤트는 순서대로 반영)
+        sqlx::query!(
+            "INSERT INTO projection_offsets (aggregate_id, last_event_id, last_version)
+            VALUES ($1, 0, 0)
+            ON CONFLICT (aggregate_id) DO NOTHING",
+            event.aggregate_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        let last_version = sqlx::query_scalar!(
+            "SELECT last_version FROM projection_offsets WHERE aggregate_id = $1 FOR UPDATE",
+            event.aggregate_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if event.version <= last_version {
+            info!(
+                "{:<12} --> 이미 반영된 이벤트 건너뜀: aggregate_id={}, version={}, 반영 버전={}",
+                "EventConsume", event.aggregate_id, event.version, last_version
+            );
+            tx.commit().await?;
+            return Ok(Vec::new());
+        }
+
+        // 버전 누락 확인 및 누락된 이벤트 조회
+        let mut events = Vec::new();
+        if event.version > last_version + 1 {
+            warn!(
+                "{:<12} --> 이벤트 버전 누락: aggregate_id={}, 반영 버전={}, 수신 버전={}",
+                "EventConsume", event.aggregate_id, last_version, event.version
+            );
+            events = sqlx::query_as::<_, Event>(
+                "SELECT id, aggregate_id, event_type, data, timestamp, version, idempotency_key, position
+                FROM events
+                WHERE aggregate_id = $1 AND version > $2 AND version < $3
+                ORDER BY version",
+            )
+            .bind(event.aggregate_id)
+            .bind(last_version)
+            .bind(event.version)
+            .fetch_all(&mut *tx)
+            .await?;
+
+            // 누락된 이벤트를 모두 찾지 못하면 반영하지 않음 (재시도 후에도 실패하면 데드레터로 전송)
+            if events.len() as i64 != event.version - last_version - 1 {
+                return Err(format!(
+                    "누락된 이벤트를 찾을 수 없음: aggregate_id={}, version {}~{}",
+                    event.aggregate_id,
+                    last_version + 1,
+                    event.version - 1
+                )
+                .into());
+            }
+        }
+        events.push(event);
+
+        for event in &events {
+            Self::apply_event(&mut tx, event).await?;
+        }
+
+        // 마지막으로 반영한 이벤트 기록
+        let event = &events[events.len() - 1];
+        sqlx::query!(
+            "UPDATE projection_offsets
+            SET last_event_id = $1, last_version = $2, updated_at = CURRENT_TIMESTAMP
+            WHERE aggregate_id = $3",
+            event.id,
+            event.version,
+            event.aggregate_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(events)
+    }
+
+    /// 데드레터 기록
+    async fn record_dead_letter(
+        db_manager: &DatabaseManager,
+        dead_letter: DeadLetter,
+    ) -> Result<(), AuctionError> {
+        sqlx::query(
+            "INSERT INTO dead_letters (topic, partition_id, message_offset, message_key, payload, error, retry_count)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(&dead_letter.topic)
+        .bind(dead_letter.partition)
+        .bind(dead_letter.offset)
+        .bind(&dead_letter.message_key)
+        .bind(&dead_letter.payload)
+        .bind(&dead_letter.error)
+        .bind(dead_letter.retry_count as i32)
+        .execute(db_manager.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// 데드레터 재처리
+    /// 원인 수정 후 호출하며, 성공하면 재처리 완료로 표시하고 실패하면 오류 사유와 재시도 횟수 갱신
+    /// 재처리로 반영한 이벤트도 실시간 구독자에게 알림
+    pub async fn replay_dead_letter(
+        db_manager: &DatabaseManager,
+        updates: &AuctionUpdates,
+        dead_letter_id: i64,
+    ) -> Result<(), AuctionError> {
+        let dead_letter = get_dead_letter(db_manager, dead_letter_id)
+            .await
+            .map_err(|e| AuctionError::from_query(e, "resource.dead_letter", dead_letter_id))?;
+        if dead_letter.replayed_at.is_some() {
+            return Err(AuctionError::AlreadyReplayed);
+        }
+
+        let result = match serde_json::from_str::<Event>(&dead_letter.payload) {
+            Ok(event) => Self::process_event(db_manager, event)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(format!("deserialize 오류: {}", e)),
+        };
+
+        match result {
+            Ok(applied) => {
+                sqlx::query(
+                    "UPDATE dead_letters SET replayed_at = CURRENT_TIMESTAMP WHERE id = $1",
+                )
+                .bind(dead_letter_id)
+                .execute(db_manager.pool())
+                .await?;
+                info!(
+                    "{:<12} --> 데드레터 재처리 성공: id={}",
+                    "EventConsume", dead_letter_id
+                );
+                updates.publish(applied);
+                Ok(())
+            }
+            Err(error) => {
+                sqlx::query(
+                    "UPDATE dead_letters SET error = $1, retry_count = retry_count + 1 WHERE id = $2",
+                )
+                .bind(&error)
+                .bind(dead_letter_id)
+                .execute(db_manager.pool())
+                .await?;
+                Err(AuctionError::ReplayFailed(error))
+            }
+        }
+    }
+
+    /// 이벤트 타입별 조회 모델 반영
+    async fn apply_event(
+        tx: &mut Transaction<'_, Postgres>,
+        event: &Event,
     ) -> Result<(), Box<dyn std::error::Error>> {
         match event.event_type.as_str() {
-            "BidPlaced" => Self::handle_bid_placed(db_manager, &event).await?,
-            "BuyNowExecuted" => Self::handle_buy_now_executed(db_manager, &event).await?,
+            "BidPlaced" | "AutoBidPlaced" => Self::handle_bid_placed(tx, event).await?,
+            "BuyNowExecuted" => Self::handle_buy_now_executed(tx, event).await?,
+            "BidRetracted" => Self::handle_bid_retracted(tx, event).await?,
+            "ProxyBidRegistered" => Self::handle_proxy_bid_registered(event)?,
+            "AuctionExtended" => Self::handle_auction_extended(tx, event).await?,
+            "PriceDropped" => Self::handle_price_dropped(tx, event).await?,
+            "SealedBidSubmitted" => Self::handle_sealed_bid_submitted(tx, event).await?,
+            "AuctionStarted" => Self::handle_auction_started(tx, event).await?,
+            "AuctionClosed" => Self::handle_auction_closed(tx, event).await?,
+            "ItemListed" => Self::handle_item_listed(tx, event).await?,
+            "ItemUpdated" => Self::handle_item_updated(tx, event).await?,
+            "ItemWithdrawn" => Self::handle_item_withdrawn(tx, event).await?,
+            "AuctionCancelled" => Self::handle_auction_cancelled(tx, event).await?,
             _ => warn!(
                 "{:<12} --> 알 수 없는 이벤트 타입: {}",
                 "EventConsume", event.event_type
@@ -136,65 +487,469 @@ impl EventConsumer {
         Ok(())
     }
 
-    /// 입찰 이벤트 처리
+    /// 입찰 이벤트 처리 (수동 입찰 및 자동 입찰)
     async fn handle_bid_placed(
-        db_manager: &DatabaseManager,
+        tx: &mut Transaction<'_, Postgres>,
         event: &Event,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        info!("{:<12} --> 입찰(BidPlaced)", "EventConsume");
+        info!("{:<12} --> 입찰({})", "EventConsume", event.event_type);
         let bid_event: AuctionEvent = serde_json::from_value(event.data.clone())?;
-        if let AuctionEvent::BidPlaced {
+        let (item_id, bidder_id, bid_amount, timestamp, is_auto) = match bid_event {
+            AuctionEvent::BidPlaced {
+                item_id,
+                bidder_id,
+                bid_amount,
+                timestamp,
+            } => (item_id, bidder_id, bid_amount, timestamp, false),
+            AuctionEvent::AutoBidPlaced {
+                item_id,
+                bidder_id,
+                bid_amount,
+                timestamp,
+            } => (item_id, bidder_id, bid_amount, timestamp, true),
+            _ => return Ok(()),
+        };
+
+        // 현재 가격 확인 및 업데이트 (유효한 입찰이 없으면 현재 가격과 같은 첫 입찰 반영)
+        let result = sqlx::query!(
+            "UPDATE items SET current_price = $1 WHERE id = $2
+            AND (current_price < $1 OR (current_price = $1 AND NOT EXISTS (SELECT 1 FROM bids WHERE item_id = $2 AND retracted_at IS NULL)))
+            RETURNING current_price",
+            bid_amount,
+            item_id
+        )
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        if let Some(row) = result {
+            // 입찰 기록 추가
+            sqlx::query!(
+                "INSERT INTO bids (item_id, bidder_id, bid_amount, bid_time, is_auto, event_version) VALUES ($1, $2, $3, $4, $5, $6)",
+                item_id,
+                bidder_id,
+                bid_amount,
+                timestamp,
+                is_auto,
+                event.version
+            )
+            .execute(&mut **tx)
+            .await?;
+
+            info!(
+                "{:<12} --> 입찰 성공: 현재 가격 {}",
+                "EventConsume", row.current_price
+            );
+        } else {
+            info!(
+                "{:<12} --> 입찰 실패: 현재 가격이 더 높거나 같음",
+                "EventConsume"
+            );
+        }
+        Ok(())
+    }
+
+    /// 비공개 입찰 제출 이벤트 처리
+    /// 입찰자별 하나의 입찰만 유지하므로 이전 입찰을 대체하며, 종료 전까지 현재 가격은 변경하지 않음
+    async fn handle_sealed_bid_submitted(
+        tx: &mut Transaction<'_, Postgres>,
+        event: &Event,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        info!("{:<12} --> 비공개 입찰(SealedBidSubmitted)", "EventConsume");
+        if let AuctionEvent::SealedBidSubmitted {
             item_id,
             bidder_id,
             bid_amount,
             timestamp,
-        } = bid_event
+        } = serde_json::from_value(event.data.clone())?
         {
-            db_manager
-                .transaction::<_, _, sqlx::Error>(|tx| {
-                    Box::pin(async move {
-                        // 현재 가격 확인 및 업데이트
-                        let result = sqlx::query!(
-                            "UPDATE items SET current_price = $1 WHERE id = $2 AND current_price < $1 RETURNING current_price",
-                            bid_amount,
-                            item_id
-                        )
-                        .fetch_optional(&mut **tx)
-                        .await?;
-
-                        if let Some(row) = result {
-                            // 입찰 기록 추가
-                            sqlx::query!(
-                                "INSERT INTO bids (item_id, bidder_id, bid_amount, bid_time) VALUES ($1, $2, $3, $4)",
-                                item_id,
-                                bidder_id,
-                                bid_amount,
-                                timestamp
-                            )
-                            .execute(&mut **tx)
-                            .await?;
-
-                            info!(
-                                "{:<12} --> 입찰 성공: 현재 가격 {}",
-                                "EventConsume", row.current_price
-                            );
-                        } else {
-                            info!(
-                                "{:<12} --> 입찰 실패: 현재 가격이 더 높거나 같음",
-                                "EventConsume"
-                            );
-                        }
-                        Ok(())
-                    })
-                })
-                .await?;
+            sqlx::query!(
+                "DELETE FROM bids WHERE item_id = $1 AND bidder_id = $2",
+                item_id,
+                bidder_id
+            )
+            .execute(&mut **tx)
+            .await?;
+
+            sqlx::query!(
+                "INSERT INTO bids (item_id, bidder_id, bid_amount, bid_time, event_version) VALUES ($1, $2, $3, $4, $5)",
+                item_id,
+                bidder_id,
+                bid_amount,
+                timestamp,
+                event.version
+            )
+            .execute(&mut **tx)
+            .await?;
+
+            info!(
+                "{:<12} --> 비공개 입찰 성공: item_id={}, bidder_id={}",
+                "EventConsume", item_id, bidder_id
+            );
+        }
+        Ok(())
+    }
+
+    /// 가격 하락 이벤트 처리 (네덜란드식 경매)
+    async fn handle_price_dropped(
+        tx: &mut Transaction<'_, Postgres>,
+        event: &Event,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        info!("{:<12} --> 가격 하락(PriceDropped)", "EventConsume");
+        if let AuctionEvent::PriceDropped {
+            item_id, new_price, ..
+        } = serde_json::from_value(event.data.clone())?
+        {
+            sqlx::query!(
+                "UPDATE items SET current_price = $1 WHERE id = $2 AND status = 'ACTIVE'",
+                new_price,
+                item_id
+            )
+            .execute(&mut **tx)
+            .await?;
+
+            info!(
+                "{:<12} --> 가격 하락 성공: item_id={}, 현재 가격 {}",
+                "EventConsume", item_id, new_price
+            );
+        }
+        Ok(())
+    }
+
+    /// 입찰 철회 이벤트 처리
+    /// 입찰 기록은 삭제하지 않고 철회 시각을 기록하며, 현재 가격은 남은 유효 입찰 중 최고 금액(없으면 시작 가격)으로 재계산
+    async fn handle_bid_retracted(
+        tx: &mut Transaction<'_, Postgres>,
+        event: &Event,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        info!("{:<12} --> 입찰 철회(BidRetracted)", "EventConsume");
+        if let AuctionEvent::BidRetracted {
+            item_id,
+            bidder_id,
+            bid_amount,
+            bid_version,
+            timestamp,
+        } = serde_json::from_value(event.data.clone())?
+        {
+            // 이벤트 버전이 기록되지 않은 이전 입찰은 입찰자와 금액으로 식별
+            sqlx::query!(
+                "UPDATE bids SET retracted_at = $1
+                WHERE item_id = $2 AND retracted_at IS NULL
+                AND (event_version = $3 OR (event_version IS NULL AND bidder_id = $4 AND bid_amount = $5))",
+                timestamp,
+                item_id,
+                bid_version,
+                bidder_id,
+                bid_amount
+            )
+            .execute(&mut **tx)
+            .await?;
+
+            let row = sqlx::query!(
+                "UPDATE items SET current_price = COALESCE(
+                    (SELECT MAX(bid_amount) FROM bids WHERE item_id = $1 AND retracted_at IS NULL),
+                    starting_price
+                )
+                WHERE id = $1
+                RETURNING current_price",
+                item_id
+            )
+            .fetch_one(&mut **tx)
+            .await?;
+
+            info!(
+                "{:<12} --> 입찰 철회 성공: item_id={}, bidder_id={}, 현재 가격 {}",
+                "EventConsume", item_id, bidder_id, row.current_price
+            );
+        }
+        Ok(())
+    }
+
+    /// 자동 입찰 등록 이벤트 처리
+    /// 최대 입찰가는 공개되지 않으므로 조회 모델에 반영하지 않음
+    fn handle_proxy_bid_registered(event: &Event) -> Result<(), Box<dyn std::error::Error>> {
+        info!(
+            "{:<12} --> 자동 입찰 등록(ProxyBidRegistered)",
+            "EventConsume"
+        );
+        if let AuctionEvent::ProxyBidRegistered {
+            item_id, bidder_id, ..
+        } = serde_json::from_value(event.data.clone())?
+        {
+            info!(
+                "{:<12} --> 자동 입찰 등록: item_id={}, bidder_id={}",
+                "EventConsume", item_id, bidder_id
+            );
+        }
+        Ok(())
+    }
+
+    /// 경매 연장 이벤트 처리
+    async fn handle_auction_extended(
+        tx: &mut Transaction<'_, Postgres>,
+        event: &Event,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        info!("{:<12} --> 경매 연장(AuctionExtended)", "EventConsume");
+        if let AuctionEvent::AuctionExtended {
+            item_id,
+            new_end_time,
+            ..
+        } = serde_json::from_value(event.data.clone())?
+        {
+            // 종료 시간은 늦춰지는 방향으로만 반영
+            sqlx::query!(
+                "UPDATE items SET end_time = $1 WHERE id = $2 AND end_time < $1",
+                new_end_time,
+                item_id
+            )
+            .execute(&mut **tx)
+            .await?;
+
+            info!(
+                "{:<12} --> 경매 연장 성공: item_id={}, 종료 시간 {}",
+                "EventConsume", item_id, new_end_time
+            );
+        }
+        Ok(())
+    }
+
+    /// 경매 시작 이벤트 처리
+    async fn handle_auction_started(
+        tx: &mut Transaction<'_, Postgres>,
+        event: &Event,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        info!("{:<12} --> 경매 시작(AuctionStarted)", "EventConsume");
+        if let AuctionEvent::AuctionStarted { item_id, .. } =
+            serde_json::from_value(event.data.clone())?
+        {
+            sqlx::query!(
+                "UPDATE items SET status = 'ACTIVE' WHERE id = $1 AND status = 'SCHEDULED'",
+                item_id
+            )
+            .execute(&mut **tx)
+            .await?;
+
+            info!(
+                "{:<12} --> 경매 시작 성공: item_id={}",
+                "EventConsume", item_id
+            );
+        }
+        Ok(())
+    }
+
+    /// 경매 종료 이벤트 처리
+    async fn handle_auction_closed(
+        tx: &mut Transaction<'_, Postgres>,
+        event: &Event,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        info!("{:<12} --> 경매 종료(AuctionClosed)", "EventConsume");
+        if let AuctionEvent::AuctionClosed {
+            item_id,
+            winner_id,
+            final_price,
+            reserve_met,
+            ..
+        } = serde_json::from_value(event.data.clone())?
+        {
+            let status = if reserve_met {
+                "COMPLETED"
+            } else {
+                "UNSOLD_RESERVE_NOT_MET"
+            };
+            // 비공개 입찰 경매는 종료 시 낙찰가를 현재 가격으로 공개
+            sqlx::query!(
+                "UPDATE items SET status = $1, winner_id = $2, current_price = COALESCE($3, current_price)
+                WHERE id = $4 AND status = 'ACTIVE'",
+                status,
+                winner_id,
+                final_price,
+                item_id
+            )
+            .execute(&mut **tx)
+            .await?;
+
+            info!(
+                "{:<12} --> 경매 종료 성공: item_id={}, 상태 {}, 낙찰자 {:?}",
+                "EventConsume", item_id, status, winner_id
+            );
+        }
+        Ok(())
+    }
+
+    /// 상품 등록 이벤트 처리
+    async fn handle_item_listed(
+        tx: &mut Transaction<'_, Postgres>,
+        event: &Event,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        info!("{:<12} --> 상품 등록(ItemListed)", "EventConsume");
+        if let AuctionEvent::ItemListed {
+            item_id,
+            title,
+            description,
+            seller,
+            starting_price,
+            buy_now_price,
+            reserve_price,
+            bid_increments,
+            start_time,
+            end_time,
+            soft_close_window_secs,
+            soft_close_extension_secs,
+            auction_type,
+            price_drop_amount,
+            price_drop_interval_secs,
+            timestamp,
+        } = serde_json::from_value(event.data.clone())?
+        {
+            // 재전달된 이벤트는 이미 등록된 상품이므로 무시
+            sqlx::query(
+                "INSERT INTO items (id, title, description, starting_price, current_price, buy_now_price,
+                    reserve_price, bid_increments, start_time, end_time, soft_close_window_secs,
+                    soft_close_extension_secs, auction_type, price_drop_amount, price_drop_interval_secs,
+                    seller, status, created_at)
+                VALUES ($1, $2, $3, $4, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, 'SCHEDULED', $16)
+                ON CONFLICT (id) DO NOTHING",
+            )
+            .bind(item_id)
+            .bind(&title)
+            .bind(&description)
+            .bind(starting_price)
+            .bind(buy_now_price)
+            .bind(reserve_price)
+            .bind(bid_increments.map(Json))
+            .bind(start_time)
+            .bind(end_time)
+            .bind(soft_close_window_secs)
+            .bind(soft_close_extension_secs)
+            .bind(&auction_type)
+            .bind(price_drop_amount)
+            .bind(price_drop_interval_secs)
+            .bind(&seller)
+            .bind(timestamp)
+            .execute(&mut **tx)
+            .await?;
+
+            info!(
+                "{:<12} --> 상품 등록 성공: item_id={}, 판매자 {}",
+                "EventConsume", item_id, seller
+            );
+        }
+        Ok(())
+    }
+
+    /// 상품 수정 이벤트 처리 (경매 시작 전이므로 현재 가격은 시작 가격)
+    async fn handle_item_updated(
+        tx: &mut Transaction<'_, Postgres>,
+        event: &Event,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        info!("{:<12} --> 상품 수정(ItemUpdated)", "EventConsume");
+        if let AuctionEvent::ItemUpdated {
+            item_id,
+            title,
+            description,
+            starting_price,
+            buy_now_price,
+            reserve_price,
+            bid_increments,
+            start_time,
+            end_time,
+            soft_close_window_secs,
+            soft_close_extension_secs,
+            auction_type,
+            price_drop_amount,
+            price_drop_interval_secs,
+            ..
+        } = serde_json::from_value(event.data.clone())?
+        {
+            sqlx::query(
+                "UPDATE items SET title = $1, description = $2, starting_price = $3, current_price = $3,
+                    buy_now_price = $4, reserve_price = $5, bid_increments = $6, start_time = $7,
+                    end_time = $8, soft_close_window_secs = $9, soft_close_extension_secs = $10,
+                    auction_type = $11, price_drop_amount = $12, price_drop_interval_secs = $13
+                WHERE id = $14 AND status = 'SCHEDULED'",
+            )
+            .bind(&title)
+            .bind(&description)
+            .bind(starting_price)
+            .bind(buy_now_price)
+            .bind(reserve_price)
+            .bind(bid_increments.map(Json))
+            .bind(start_time)
+            .bind(end_time)
+            .bind(soft_close_window_secs)
+            .bind(soft_close_extension_secs)
+            .bind(&auction_type)
+            .bind(price_drop_amount)
+            .bind(price_drop_interval_secs)
+            .bind(item_id)
+            .execute(&mut **tx)
+            .await?;
+
+            info!(
+                "{:<12} --> 상품 수정 성공: item_id={}",
+                "EventConsume", item_id
+            );
+        }
+        Ok(())
+    }
+
+    /// 상품 철회 이벤트 처리
+    async fn handle_item_withdrawn(
+        tx: &mut Transaction<'_, Postgres>,
+        event: &Event,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        info!("{:<12} --> 상품 철회(ItemWithdrawn)", "EventConsume");
+        if let AuctionEvent::ItemWithdrawn { item_id, .. } =
+            serde_json::from_value(event.data.clone())?
+        {
+            sqlx::query!(
+                "UPDATE items SET status = 'WITHDRAWN' WHERE id = $1 AND status = 'SCHEDULED'",
+                item_id
+            )
+            .execute(&mut **tx)
+            .await?;
+
+            info!(
+                "{:<12} --> 상품 철회 성공: item_id={}",
+                "EventConsume", item_id
+            );
+        }
+        Ok(())
+    }
+
+    /// 경매 취소 이벤트 처리
+    async fn handle_auction_cancelled(
+        tx: &mut Transaction<'_, Postgres>,
+        event: &Event,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        info!("{:<12} --> 경매 취소(AuctionCancelled)", "EventConsume");
+        if let AuctionEvent::AuctionCancelled {
+            item_id,
+            reason,
+            bidder_ids,
+            ..
+        } = serde_json::from_value(event.data.clone())?
+        {
+            sqlx::query!(
+                "UPDATE items SET status = 'CANCELLED' WHERE id = $1 AND status IN ('SCHEDULED', 'ACTIVE')",
+                item_id
+            )
+            .execute(&mut **tx)
+            .await?;
+
+            info!(
+                "{:<12} --> 경매 취소 성공: item_id={}, 사유 {}, 입찰자 {}명",
+                "EventConsume",
+                item_id,
+                reason,
+                bidder_ids.len()
+            );
         }
         Ok(())
     }
 
     /// 즉시 구매 이벤트 처리
     async fn handle_buy_now_executed(
-        db_manager: &DatabaseManager,
+        tx: &mut Transaction<'_, Postgres>,
         event: &Event,
     ) -> Result<(), Box<dyn std::error::Error>> {
         info!("{:<12} --> 즉시 구매(BuyNowExecuted)", "EventConsume");
@@ -206,44 +961,44 @@ impl EventConsumer {
             timestamp,
         } = buy_now_event
         {
-            db_manager
-                .transaction::<_, _, sqlx::Error>(|tx| {
-                    Box::pin(async move {
-                        // 현재 가격 확인 및 상태 업데이트
-                        let result = sqlx::query!(
-                            "UPDATE items SET current_price = $1, status = 'COMPLETED' WHERE id = $2 AND current_price < $1 AND status != 'COMPLETED' RETURNING current_price",
-                            price,
-                            item_id
-                        )
-                        .fetch_optional(&mut **tx)
-                        .await?;
-
-                        if let Some(row) = result {
-                            // 즉시 구매 기록 추가
-                            sqlx::query!(
-                                "INSERT INTO bids (item_id, bidder_id, bid_amount, bid_time) VALUES ($1, $2, $3, $4)",
-                                item_id,
-                                buyer_id,
-                                price,
-                                timestamp
-                            )
-                            .execute(&mut **tx)
-                            .await?;
-
-                            info!(
-                                "{:<12} --> 즉시 구매 성공: 최종 가격 {}",
-                                "EventConsume", row.current_price
-                            );
-                        } else {
-                            info!(
-                                "{:<12} --> 즉시 구매 실패: 현재 가격이 더 높거나 같음, 또는 이미 완료된 경매",
-                                "EventConsume"
-                            );
-                        }
-                        Ok(())
-                    })
-                })
+            // 현재 가격 확인 및 상태 업데이트 (네덜란드식 경매는 현재 가격으로 낙찰)
+            // 입찰과 같이 유효한 입찰이 없으면 현재 가격과 같은 즉시 구매 가격도 반영 (시작 가격과 즉시 구매 가격이 같은 상품)
+            let result = sqlx::query!(
+                "UPDATE items SET current_price = $1, status = 'COMPLETED', winner_id = $3 WHERE id = $2
+                AND (current_price < $1 OR auction_type = 'DUTCH'
+                    OR (current_price = $1 AND NOT EXISTS (SELECT 1 FROM bids WHERE item_id = $2 AND retracted_at IS NULL)))
+                AND status != 'COMPLETED'
+                RETURNING current_price",
+                price,
+                item_id,
+                buyer_id
+            )
+            .fetch_optional(&mut **tx)
+            .await?;
+
+            if let Some(row) = result {
+                // 즉시 구매 기록 추가
+                sqlx::query!(
+                    "INSERT INTO bids (item_id, bidder_id, bid_amount, bid_time, event_version) VALUES ($1, $2, $3, $4, $5)",
+                    item_id,
+                    buyer_id,
+                    price,
+                    timestamp,
+                    event.version
+                )
+                .execute(&mut **tx)
                 .await?;
+
+                info!(
+                    "{:<12} --> 즉시 구매 성공: 최종 가격 {}",
+                    "EventConsume", row.current_price
+                );
+            } else {
+                info!(
+                    "{:<12} --> 즉시 구매 실패: 현재 가격이 더 높거나 같음, 또는 이미 완료된 경매",
+                    "EventConsume"
+                );
+            }
         }
         Ok(())
     }
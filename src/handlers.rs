@@ -1,143 +1,413 @@
 // region:    --- Imports
+use crate::auction::aggregate::AuctionAggregate;
+use crate::auction::commands::{
+    handle_cancel_auction as command_handle_cancel_auction,
+    handle_list_item as command_handle_list_item, handle_update_item as command_handle_update_item,
+    handle_withdraw_item as command_handle_withdraw_item, CancelAuctionCommand, ListItemCommand,
+    UpdateItemCommand, WithdrawItemCommand,
+};
 use crate::bidding::commands::{
-    handle_buy_now as command_handle_buy_now, handle_place_bid, BuyNowCommand, PlaceBidCommand,
+    handle_buy_now as command_handle_buy_now, handle_place_bid, handle_retract_bid, BuyNowCommand,
+    PlaceBidCommand, RetractBidCommand,
 };
+use crate::bidding::model::{Bid, SealedBid};
 use crate::database::DatabaseManager;
-use crate::event_store::PostgresEventStore;
-use crate::message_broker::KafkaProducer;
+use crate::error::AuctionError;
+use crate::event_store::{EventConsumer, PostgresEventStore};
+use crate::i18n::Locale;
+use crate::idempotency;
 use crate::query;
-use axum::extract::{Path, State};
-use axum::response::IntoResponse;
+use crate::query::repository::PostgresRepository;
+use crate::realtime::{self, sse, AuctionUpdates};
+use axum::extract::ws::WebSocketUpgrade;
+use axum::extract::{Extension, Path, Query, Request, State};
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
 use axum::Json;
-use chrono::Utc;
+use serde::Deserialize;
 use std::sync::Arc;
-use tracing::info;
+use tracing::{info, warn};
 
 // endregion: --- Imports
 
 // region:    --- Command Handlers
 
+/// 판매자 요청(상품 등록, 수정, 철회, 경매 취소)의 판매자 헤더
+/// 인증은 앞단의 API 게이트웨이가 수행하고 인증된 판매자를 이 헤더로 전달한다고 가정 (서비스는 헤더 값을 그대로 신뢰)
+/// 게이트웨이 없이 노출하면 누구나 헤더를 지정할 수 있으므로 판매자 확인은 보안 수단이 아님
+const SELLER_HEADER: &str = "x-seller";
+
 /// 입찰 요청 처리
+/// `Idempotency-Key` 헤더가 있으면 같은 키의 재요청에 저장된 응답 반환
 pub async fn handle_bid(
-    State((db_manager, kafka_producer)): State<(Arc<DatabaseManager>, Arc<KafkaProducer>)>,
+    State(db_manager): State<Arc<DatabaseManager>>,
+    headers: HeaderMap,
     Json(cmd): Json<PlaceBidCommand>,
 ) -> impl IntoResponse {
     info!("{:<12} --> 입찰 요청 처리 시작: {:?}", "Command", cmd);
 
-    // 이벤트 저장소 생성
-    let event_store = PostgresEventStore::new(Arc::clone(&db_manager), Arc::clone(&kafka_producer));
+    let locale = Locale::from_headers(&headers);
+    let request = serde_json::to_value(&cmd).unwrap_or_default();
+    idempotency::run(
+        &db_manager,
+        locale,
+        idempotency::idempotency_key(&headers),
+        "/bid",
+        request,
+        |idempotency_key| {
+            place_bid(
+                &db_manager,
+                locale,
+                PlaceBidCommand {
+                    idempotency_key,
+                    ..cmd
+                },
+            )
+        },
+    )
+    .await
+}
 
-    let item_id = cmd.item_id;
+/// 입찰 처리
+async fn place_bid(
+    db_manager: &Arc<DatabaseManager>,
+    locale: Locale,
+    cmd: PlaceBidCommand,
+) -> Response {
+    // 이벤트 저장소 생성
+    let event_store = PostgresEventStore::new(Arc::clone(db_manager));
+    let repository = PostgresRepository::new(Arc::clone(db_manager));
 
-    // 현재 가격 조회
-    let current_price = match query::handlers::get_item_current_price(&db_manager, item_id).await {
-        Ok(price) => price,
-        Err(e) => {
-            return (
-                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
-                Json(e.to_string()),
-            )
-                .into_response()
-        }
-    };
+    let bid_amount = cmd.bid_amount;
 
-    // 입찰 가격이 현재 가격보다 높은지 검증
-    if cmd.bid_amount <= current_price {
-        return (
-            axum::http::StatusCode::BAD_REQUEST,
+    // 입찰 처리 (현재 가격 검증은 경매 애그리거트 기준으로 명령에서 수행)
+    match handle_place_bid(cmd, &event_store, &repository).await {
+        Ok(updated_item) => (
+            StatusCode::OK,
+            [(header::CONTENT_LANGUAGE, locale.content_language())],
             Json(serde_json::json!({
-                "error": "입찰 가격은 현재 가격보다 높아야 합니다.",
-                "current_price": current_price
+                "message": locale.message("BID_ACCEPTED"),
+                "current_price": updated_item.current_price,
+                "bid_amount": bid_amount,
+                "reserve_met": updated_item.is_reserve_met()
             })),
         )
-            .into_response();
+            .into_response(),
+        Err(e) => e.to_response(locale),
     }
+}
 
-    let bid_amount = cmd.bid_amount;
+/// 입찰 철회 요청 처리
+/// `Idempotency-Key` 헤더가 있으면 같은 키의 재요청에 저장된 응답 반환 (같은 요청으로 이전 입찰까지 철회되지 않도록)
+pub async fn handle_retract(
+    State(db_manager): State<Arc<DatabaseManager>>,
+    headers: HeaderMap,
+    Json(cmd): Json<RetractBidCommand>,
+) -> impl IntoResponse {
+    info!("{:<12} --> 입찰 철회 요청 처리 시작: {:?}", "Command", cmd);
 
-    // 입찰 처리
-    match handle_place_bid(cmd, &event_store, &db_manager).await {
-        Ok(_) => {
-            let updated_item = query::handlers::get_item(&db_manager, item_id)
-                .await
-                .unwrap();
-            (
-                axum::http::StatusCode::OK,
-                Json(serde_json::json!({
-                    "message": "입찰이 성공적으로 처리되었습니다.",
-                    "current_price": updated_item.current_price,
-                    "bid_amount": bid_amount
-                })),
+    let locale = Locale::from_headers(&headers);
+    let request = serde_json::to_value(&cmd).unwrap_or_default();
+    idempotency::run(
+        &db_manager,
+        locale,
+        idempotency::idempotency_key(&headers),
+        "/bid/retract",
+        request,
+        |idempotency_key| {
+            retract_bid(
+                &db_manager,
+                locale,
+                RetractBidCommand {
+                    idempotency_key,
+                    ..cmd
+                },
             )
-                .into_response()
-        }
-        Err(e) => (axum::http::StatusCode::BAD_REQUEST, Json(e)).into_response(),
+        },
+    )
+    .await
+}
+
+/// 입찰 철회 처리
+async fn retract_bid(
+    db_manager: &Arc<DatabaseManager>,
+    locale: Locale,
+    cmd: RetractBidCommand,
+) -> Response {
+    let event_store = PostgresEventStore::new(Arc::clone(db_manager));
+    let repository = PostgresRepository::new(Arc::clone(db_manager));
+
+    // 철회 정책 검증 및 현재 가격 재계산은 경매 애그리거트 기준으로 명령에서 수행
+    match handle_retract_bid(cmd, &event_store, &repository).await {
+        Ok(updated_item) => (
+            StatusCode::OK,
+            [(header::CONTENT_LANGUAGE, locale.content_language())],
+            Json(serde_json::json!({
+                "message": locale.message("BID_RETRACTED"),
+                "current_price": updated_item.current_price,
+                "reserve_met": updated_item.is_reserve_met()
+            })),
+        )
+            .into_response(),
+        Err(e) => e.to_response(locale),
     }
 }
 
 /// 즉시 구매 요청 처리
+/// `Idempotency-Key` 헤더가 있으면 같은 키의 재요청에 저장된 응답 반환
 pub async fn handle_buy_now(
-    State((db_manager, kafka_producer)): State<(Arc<DatabaseManager>, Arc<KafkaProducer>)>,
+    State(db_manager): State<Arc<DatabaseManager>>,
+    headers: HeaderMap,
     Json(cmd): Json<BuyNowCommand>,
 ) -> impl IntoResponse {
     info!("{:<12} --> 즉시 구매 요청 처리 시작: {:?}", "Command", cmd);
 
+    let locale = Locale::from_headers(&headers);
+    let request = serde_json::to_value(&cmd).unwrap_or_default();
+    idempotency::run(
+        &db_manager,
+        locale,
+        idempotency::idempotency_key(&headers),
+        "/buy-now",
+        request,
+        |idempotency_key| {
+            buy_now(
+                &db_manager,
+                locale,
+                BuyNowCommand {
+                    idempotency_key,
+                    ..cmd
+                },
+            )
+        },
+    )
+    .await
+}
+
+/// 즉시 구매 처리
+async fn buy_now(
+    db_manager: &Arc<DatabaseManager>,
+    locale: Locale,
+    cmd: BuyNowCommand,
+) -> Response {
     // 이벤트 저장소 생성
-    let event_store = PostgresEventStore::new(Arc::clone(&db_manager), Arc::clone(&kafka_producer));
-    // 아이템 상태 확인
-    let item = match query::handlers::get_item(&db_manager, cmd.item_id).await {
-        Ok(item) => item,
-        Err(e) => {
-            return (
-                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({
-                    "error": format!("Failed to fetch item: {}", e)
-                })),
+    let event_store = PostgresEventStore::new(Arc::clone(db_manager));
+    let repository = PostgresRepository::new(Arc::clone(db_manager));
+
+    // 즉시 구매 처리 (경매 시작 여부 및 즉시 구매 가격은 경매 애그리거트 기준)
+    match command_handle_buy_now(cmd, &event_store, &repository).await {
+        Ok(_) => (
+            StatusCode::OK,
+            [(header::CONTENT_LANGUAGE, locale.content_language())],
+            locale.message("BUY_NOW_EXECUTED"),
+        )
+            .into_response(),
+        Err(e) => e.to_response(locale),
+    }
+}
+
+/// 상품 등록 요청 처리
+/// 등록한 판매자는 `X-Seller` 헤더로 전달 (본문의 `seller`는 사용하지 않음)
+/// `Idempotency-Key` 헤더가 있으면 같은 키의 재요청에 저장된 응답 반환 (상품 중복 등록 방지)
+pub async fn handle_list_item(
+    State(db_manager): State<Arc<DatabaseManager>>,
+    headers: HeaderMap,
+    Json(cmd): Json<ListItemCommand>,
+) -> impl IntoResponse {
+    info!("{:<12} --> 상품 등록 요청 처리 시작: {:?}", "Command", cmd);
+
+    let locale = Locale::from_headers(&headers);
+    let seller = seller_from_headers(&headers);
+    let request = serde_json::to_value(&cmd).unwrap_or_default();
+    idempotency::run(
+        &db_manager,
+        locale,
+        idempotency::idempotency_key(&headers),
+        "/items",
+        request,
+        |idempotency_key| {
+            list_item(
+                &db_manager,
+                locale,
+                ListItemCommand {
+                    seller,
+                    idempotency_key,
+                    ..cmd
+                },
             )
-                .into_response()
-        }
-    };
+        },
+    )
+    .await
+}
+
+/// 상품 등록 처리
+/// 조회 모델에는 이벤트 소비 후 반영되므로 응답 직후 조회 시 아직 없을 수 있음
+async fn list_item(
+    db_manager: &Arc<DatabaseManager>,
+    locale: Locale,
+    cmd: ListItemCommand,
+) -> Response {
+    let event_store = PostgresEventStore::new(Arc::clone(db_manager));
 
-    // 경매가 아직 시작되지 않았을 경우 예외 처리
-    let now = Utc::now();
-    if now < item.start_time {
-        info!("{:<12} --> 경매가 아직 시작되지 않았습니다.", "Command");
-        return (
-            axum::http::StatusCode::BAD_REQUEST,
+    match command_handle_list_item(cmd, &event_store).await {
+        Ok(item) => (
+            StatusCode::CREATED,
+            [
+                (header::CONTENT_LANGUAGE, locale.content_language()),
+                (
+                    header::LOCATION,
+                    HeaderValue::from_str(&format!("/items/{}", item.item_id))
+                        .unwrap_or_else(|_| HeaderValue::from_static("/items")),
+                ),
+            ],
             Json(serde_json::json!({
-                "error": "경매가 아직 시작되지 않았습니다.",
-                "code": "NOT_STARTED"
+                "message": locale.message("ITEM_LISTED"),
+                "item_id": item.item_id,
+                "status": item.status
             })),
         )
-            .into_response();
+            .into_response(),
+        Err(e) => e.to_response(locale),
     }
+}
+
+/// 상품 수정 요청 처리 (경매 시작 전에만 가능)
+/// 요청한 판매자는 `X-Seller` 헤더로 전달하며, 상품 판매자가 아니면 거절
+/// `Idempotency-Key` 헤더가 있으면 같은 키의 재요청에 저장된 응답 반환
+pub async fn handle_update_item(
+    State(db_manager): State<Arc<DatabaseManager>>,
+    headers: HeaderMap,
+    Path(item_id): Path<i64>,
+    Json(cmd): Json<UpdateItemCommand>,
+) -> impl IntoResponse {
+    info!(
+        "{:<12} --> 상품 수정 요청 id: {}, {:?}",
+        "Command", item_id, cmd
+    );
+
+    let locale = Locale::from_headers(&headers);
+    let seller = seller_from_headers(&headers);
+    let request = serde_json::to_value(&cmd).unwrap_or_default();
+    idempotency::run(
+        &db_manager,
+        locale,
+        idempotency::idempotency_key(&headers),
+        &format!("/items/{}", item_id),
+        request,
+        |idempotency_key| {
+            update_item(
+                &db_manager,
+                locale,
+                UpdateItemCommand {
+                    item_id,
+                    seller,
+                    idempotency_key,
+                    ..cmd
+                },
+            )
+        },
+    )
+    .await
+}
+
+/// 상품 수정 처리
+async fn update_item(
+    db_manager: &Arc<DatabaseManager>,
+    locale: Locale,
+    cmd: UpdateItemCommand,
+) -> Response {
+    let event_store = PostgresEventStore::new(Arc::clone(db_manager));
+    let repository = PostgresRepository::new(Arc::clone(db_manager));
 
-    // 즉시 구매 처리
-    match process_buy_now(&db_manager, cmd, &event_store).await {
-        Ok(_) => (axum::http::StatusCode::OK, "Buy now executed successfully").into_response(),
-        Err(e) => (axum::http::StatusCode::BAD_REQUEST, e).into_response(),
+    match command_handle_update_item(cmd, &event_store, &repository).await {
+        Ok(item) => (
+            [(header::CONTENT_LANGUAGE, locale.content_language())],
+            Json(serde_json::json!({
+                "message": locale.message("ITEM_UPDATED"),
+                "item_id": item.item_id,
+                "status": item.status
+            })),
+        )
+            .into_response(),
+        Err(e) => e.to_response(locale),
     }
 }
 
-/// 즉시 구매 처리 프로세스
-async fn process_buy_now(
-    db_manager: &DatabaseManager,
-    cmd: BuyNowCommand,
-    event_store: &PostgresEventStore,
-) -> Result<(), String> {
+/// 상품 철회 요청 처리 (경매 시작 전에만 가능)
+/// 요청한 판매자는 `X-Seller` 헤더로 전달하며, 상품 판매자가 아니면 거절
+pub async fn handle_withdraw_item(
+    State(db_manager): State<Arc<DatabaseManager>>,
+    headers: HeaderMap,
+    Path(item_id): Path<i64>,
+) -> impl IntoResponse {
+    info!("{:<12} --> 상품 철회 요청 id: {}", "Command", item_id);
+    let locale = Locale::from_headers(&headers);
+    let event_store = PostgresEventStore::new(Arc::clone(&db_manager));
+    let repository = PostgresRepository::new(Arc::clone(&db_manager));
+
+    let cmd = WithdrawItemCommand {
+        item_id,
+        seller: seller_from_headers(&headers),
+    };
+    match command_handle_withdraw_item(cmd, &event_store, &repository).await {
+        Ok(item) => (
+            [(header::CONTENT_LANGUAGE, locale.content_language())],
+            Json(serde_json::json!({
+                "message": locale.message("ITEM_WITHDRAWN"),
+                "item_id": item.item_id,
+                "status": item.status
+            })),
+        )
+            .into_response(),
+        Err(e) => e.to_response(locale),
+    }
+}
+
+/// 경매 취소 요청 처리 (판매자, 사유 필수)
+/// 요청한 판매자는 `X-Seller` 헤더로 전달하며, 상품 판매자가 아니면 거절
+/// 취소 이벤트에는 입찰자 전원의 id가 기록되어 알림 서비스가 입찰자에게 알릴 수 있음
+pub async fn handle_cancel_auction(
+    State(db_manager): State<Arc<DatabaseManager>>,
+    headers: HeaderMap,
+    Path(item_id): Path<i64>,
+    Json(cmd): Json<CancelAuctionCommand>,
+) -> impl IntoResponse {
+    let locale = Locale::from_headers(&headers);
     info!(
-        "{:<12} --> 즉시 구매 처리 프로세스 시작: {:?}",
-        "Command", cmd
+        "{:<12} --> 경매 취소 요청 id: {}, {:?}",
+        "Command", item_id, cmd
     );
-    // 즉시 구매 가격 가져오기
-    let buy_now_price = query::handlers::get_item_buy_now_price(db_manager, cmd.item_id)
-        .await
-        .map_err(|e| e.to_string())?;
+    let event_store = PostgresEventStore::new(Arc::clone(&db_manager));
+    let repository = PostgresRepository::new(Arc::clone(&db_manager));
+
+    let cmd = CancelAuctionCommand {
+        item_id,
+        seller: seller_from_headers(&headers),
+        ..cmd
+    };
+    match command_handle_cancel_auction(cmd, &event_store, &repository).await {
+        Ok(item) => (
+            [(header::CONTENT_LANGUAGE, locale.content_language())],
+            Json(serde_json::json!({
+                "message": locale.message("AUCTION_CANCELLED"),
+                "item_id": item.item_id,
+                "status": item.status
+            })),
+        )
+            .into_response(),
+        Err(e) => e.to_response(locale),
+    }
+}
 
-    // handle_buy_now 함수 호출
-    command_handle_buy_now(cmd, buy_now_price, event_store, db_manager)
-        .await
-        .map_err(|e| e.to_string())
+/// 요청한 판매자 (`X-Seller` 헤더, 없으면 빈 문자열)
+/// 게이트웨이가 전달한 값을 그대로 사용하며, 서비스에서 인증하지 않음
+fn seller_from_headers(headers: &HeaderMap) -> String {
+    headers
+        .get(SELLER_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string()
 }
 
 // endregion: --- Command Handlers
@@ -146,69 +416,81 @@ async fn process_buy_now(
 
 /// 경매 상태 조회
 pub async fn handle_get_auction_state(
-    State((db_manager, _)): State<(Arc<DatabaseManager>, Arc<KafkaProducer>)>,
+    State(db_manager): State<Arc<DatabaseManager>>,
+    locale: Locale,
     Path(item_id): Path<i64>,
 ) -> impl IntoResponse {
     info!("{:<12} --> 경매 상태 조회 id: {}", "HandlerQuery", item_id);
     match query::handlers::get_auction_state(&db_manager, item_id).await {
         Ok(item) => Json(item).into_response(),
-        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => AuctionError::from_query(e, "resource.item", item_id).to_response(locale),
     }
 }
 
 /// 최고 입찰가 조회
 pub async fn handle_get_highest_bid(
-    State((db_manager, _)): State<(Arc<DatabaseManager>, Arc<KafkaProducer>)>,
+    State(db_manager): State<Arc<DatabaseManager>>,
+    locale: Locale,
     Path(item_id): Path<i64>,
 ) -> impl IntoResponse {
     info!(
         "{:<12} --> 최고 입찰가 조회 id: {}",
         "HandlerQuery", item_id
     );
+    // 비공개 입찰 경매는 종료 전까지 최고 입찰가를 공개하지 않음
+    match query::handlers::hides_bid_amounts(&db_manager, item_id).await {
+        Ok(true) => return Json(None::<i64>).into_response(),
+        Ok(false) => {}
+        Err(e) => return AuctionError::from(e).to_response(locale),
+    }
     match query::handlers::get_highest_bid(&db_manager, item_id).await {
         Ok(bid) => Json(bid).into_response(),
-        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => AuctionError::from(e).to_response(locale),
     }
 }
 
 /// 입찰 이력 조회
 pub async fn handle_get_bid_history(
-    State((db_manager, _)): State<(Arc<DatabaseManager>, Arc<KafkaProducer>)>,
+    State(db_manager): State<Arc<DatabaseManager>>,
+    locale: Locale,
     Path(item_id): Path<i64>,
 ) -> impl IntoResponse {
     info!("{:<12} --> 입찰 이력 조회 id: {}", "HandlerQuery", item_id);
     match query::handlers::get_bid_history(&db_manager, item_id).await {
-        Ok(history) => Json(history).into_response(),
-        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Ok(history) => bids_response(&db_manager, locale, item_id, history).await,
+        Err(e) => AuctionError::from(e).to_response(locale),
     }
 }
 
 /// 모든 상품 조회
 pub async fn handle_get_items(
-    State((db_manager, _)): State<(Arc<DatabaseManager>, Arc<KafkaProducer>)>,
+    State(db_manager): State<Arc<DatabaseManager>>,
+    locale: Locale,
 ) -> impl IntoResponse {
     info!("{:<12} --> 모든 상품 조회", "HandlerQuery");
     match query::handlers::get_all_items(&db_manager).await {
         Ok(items) => Json(items).into_response(),
-        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => AuctionError::from(e).to_response(locale),
     }
 }
 
 /// 상품 조회
 pub async fn handle_get_item(
-    State((db_manager, _)): State<(Arc<DatabaseManager>, Arc<KafkaProducer>)>,
+    State(db_manager): State<Arc<DatabaseManager>>,
+    locale: Locale,
     Path(item_id): Path<i64>,
 ) -> impl IntoResponse {
     info!("{:<12} --> 상품 조회 id: {}", "HandlerQuery", item_id);
     match query::handlers::get_item(&db_manager, item_id).await {
         Ok(item) => Json(item).into_response(),
-        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => AuctionError::from_query(e, "resource.item", item_id).to_response(locale),
     }
 }
 
 /// 상품 입찰 이력 조회
 pub async fn handle_get_item_bids(
-    State((db_manager, _)): State<(Arc<DatabaseManager>, Arc<KafkaProducer>)>,
+    State(db_manager): State<Arc<DatabaseManager>>,
+    locale: Locale,
     Path(item_id): Path<i64>,
 ) -> impl IntoResponse {
     info!(
@@ -216,9 +498,177 @@ pub async fn handle_get_item_bids(
         "HandlerQuery", item_id
     );
     match query::handlers::get_item_bids(&db_manager, item_id).await {
-        Ok(bids) => Json(bids).into_response(),
-        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Ok(bids) => bids_response(&db_manager, locale, item_id, bids).await,
+        Err(e) => AuctionError::from(e).to_response(locale),
+    }
+}
+
+/// 입찰 이력 응답 (비공개 입찰 경매는 종료 전까지 입찰 금액을 숨김)
+async fn bids_response(
+    db_manager: &DatabaseManager,
+    locale: Locale,
+    item_id: i64,
+    bids: Vec<Bid>,
+) -> Response {
+    match query::handlers::hides_bid_amounts(db_manager, item_id).await {
+        Ok(true) => Json(bids.into_iter().map(SealedBid::from).collect::<Vec<_>>()).into_response(),
+        Ok(false) => Json(bids).into_response(),
+        Err(e) => AuctionError::from(e).to_response(locale),
     }
 }
 
 // endregion: --- Query Handlers
+
+// region:    --- Admin Handlers
+
+/// 관리자 요청의 관리자 토큰 헤더
+/// 관리자 API는 내부망 또는 앞단의 API 게이트웨이를 통해서만 호출한다고 가정하며,
+/// 노출되더라도 `ADMIN_TOKEN` 환경 변수와 같은 토큰을 전달한 요청만 처리 (환경 변수가 없으면 모든 관리자 요청 거절)
+const ADMIN_TOKEN_HEADER: &str = "x-admin-token";
+
+/// 관리자 토큰 확인 미들웨어
+/// 토큰이 없거나 일치하지 않으면 `ADMIN_REQUIRED`(403) 오류로 거절
+pub async fn require_admin(request: Request, next: Next) -> Response {
+    let authorized = match std::env::var("ADMIN_TOKEN") {
+        Ok(admin_token) if !admin_token.is_empty() => request
+            .headers()
+            .get(ADMIN_TOKEN_HEADER)
+            .is_some_and(|token| constant_time_eq(token.as_bytes(), admin_token.as_bytes())),
+        _ => false,
+    };
+    if !authorized {
+        warn!(
+            "{:<12} --> 관리자 토큰 확인 실패: {}",
+            "HandlerAdmin",
+            request.uri().path()
+        );
+        return AuctionError::AdminRequired.to_response(Locale::from_headers(request.headers()));
+    }
+    next.run(request).await
+}
+
+/// 응답 시간으로 토큰이 추측되지 않도록 길이가 같으면 모든 바이트를 비교
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// 스냅샷 무효화 및 재생성 요청 처리
+/// 애그리거트 적용 로직 변경 후 배포 시 호출
+pub async fn handle_rebuild_snapshots(
+    State(db_manager): State<Arc<DatabaseManager>>,
+    locale: Locale,
+) -> impl IntoResponse {
+    info!("{:<12} --> 스냅샷 재생성 요청", "HandlerAdmin");
+    let event_store = PostgresEventStore::new(Arc::clone(&db_manager));
+    let repository = PostgresRepository::new(Arc::clone(&db_manager));
+    match AuctionAggregate::rebuild_snapshots(&event_store, &repository).await {
+        Ok(rebuilt) => Json(serde_json::json!({
+            "message": locale.message("SNAPSHOTS_REBUILT"),
+            "rebuilt": rebuilt
+        }))
+        .into_response(),
+        Err(e) => e.to_response(locale),
+    }
+}
+
+/// 재처리 대기 중인 데드레터 조회
+pub async fn handle_get_dead_letters(
+    State(db_manager): State<Arc<DatabaseManager>>,
+    locale: Locale,
+) -> impl IntoResponse {
+    info!("{:<12} --> 데드레터 조회", "HandlerAdmin");
+    match query::handlers::get_dead_letters(&db_manager).await {
+        Ok(dead_letters) => Json(dead_letters).into_response(),
+        Err(e) => AuctionError::from(e).to_response(locale),
+    }
+}
+
+/// 데드레터 재처리 요청 처리
+pub async fn handle_replay_dead_letter(
+    State(db_manager): State<Arc<DatabaseManager>>,
+    Extension(updates): Extension<Arc<AuctionUpdates>>,
+    locale: Locale,
+    Path(dead_letter_id): Path<i64>,
+) -> impl IntoResponse {
+    info!(
+        "{:<12} --> 데드레터 재처리 요청 id: {}",
+        "HandlerAdmin", dead_letter_id
+    );
+    match EventConsumer::replay_dead_letter(&db_manager, &updates, dead_letter_id).await {
+        Ok(()) => Json(serde_json::json!({
+            "message": locale.message("DEAD_LETTER_REPLAYED"),
+            "id": dead_letter_id
+        }))
+        .into_response(),
+        Err(e) => e.to_response(locale),
+    }
+}
+
+// endregion: --- Admin Handlers
+
+// region:    --- Realtime Handlers
+
+/// 실시간 구독 쿼리
+#[derive(Debug, Deserialize)]
+pub struct SubscribeQuery {
+    /// 마지막으로 받은 이벤트 버전 (재연결 시 이후 이벤트부터 수신)
+    pub last_version: Option<i64>,
+}
+
+/// 경매 실시간 구독 (WebSocket)
+/// 가격 변경, 새 입찰, 경매 연장, 종료 이벤트를 조회 모델 반영 직후 전달
+pub async fn handle_subscribe_auction(
+    State(db_manager): State<Arc<DatabaseManager>>,
+    Extension(updates): Extension<Arc<AuctionUpdates>>,
+    locale: Locale,
+    Path(item_id): Path<i64>,
+    Query(params): Query<SubscribeQuery>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    info!("{:<12} --> 경매 구독 요청 id: {}", "HandlerWs", item_id);
+    // 존재하지 않는 상품은 연결 전에 거절
+    if let Err(e) = query::handlers::get_item(&db_manager, item_id).await {
+        return AuctionError::from_query(e, "resource.item", item_id).to_response(locale);
+    }
+    ws.on_upgrade(move |socket| {
+        realtime::serve(socket, db_manager, updates, item_id, params.last_version)
+    })
+}
+
+/// 전체 상품 실시간 구독 (SSE)
+/// `Last-Event-ID` 헤더가 있으면 해당 이벤트 위치 이후부터 전달
+pub async fn handle_stream_items(
+    State(db_manager): State<Arc<DatabaseManager>>,
+    Extension(updates): Extension<Arc<AuctionUpdates>>,
+    headers: HeaderMap,
+) -> Response {
+    info!("{:<12} --> 전체 상품 SSE 구독 요청", "HandlerSse");
+    sse::stream(db_manager, &updates, None, sse::last_event_id(&headers)).into_response()
+}
+
+/// 경매 실시간 구독 (SSE)
+/// `Last-Event-ID` 헤더가 있으면 해당 이벤트 위치 이후부터 전달
+pub async fn handle_stream_auction(
+    State(db_manager): State<Arc<DatabaseManager>>,
+    Extension(updates): Extension<Arc<AuctionUpdates>>,
+    locale: Locale,
+    headers: HeaderMap,
+    Path(item_id): Path<i64>,
+) -> Response {
+    info!(
+        "{:<12} --> 경매 SSE 구독 요청 id: {}",
+        "HandlerSse", item_id
+    );
+    if let Err(e) = query::handlers::get_item(&db_manager, item_id).await {
+        return AuctionError::from_query(e, "resource.item", item_id).to_response(locale);
+    }
+    sse::stream(
+        db_manager,
+        &updates,
+        Some(item_id),
+        sse::last_event_id(&headers),
+    )
+    .into_response()
+}
+
+// endregion: --- Realtime Handlers
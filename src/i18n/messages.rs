@@ -0,0 +1,290 @@
+/// 메시지 카탈로그
+/// 키는 오류 코드(`AuctionError::code`)를 그대로 사용하고, 상세 메시지는 `<코드>.detail` 키 사용
+/// 매개변수는 `{name}` 형식으로 표시하며 `Locale::format`에서 치환
+/// 번역을 추가할 때는 모든 언어의 메시지를 함께 추가
+// region:    --- Imports
+use super::Locale;
+
+// endregion: --- Imports
+
+// region:    --- Messages
+/// (키, 한국어, 영어)
+const MESSAGES: &[(&str, &str, &str)] = &[
+    // 오류 제목
+    ("NOT_FOUND", "리소스를 찾을 수 없습니다.", "Resource not found."),
+    (
+        "NOT_STARTED",
+        "경매가 아직 시작되지 않았습니다.",
+        "The auction has not started yet.",
+    ),
+    (
+        "ALREADY_ENDED",
+        "경매가 이미 종료되었습니다.",
+        "The auction has already ended.",
+    ),
+    (
+        "INVALID_STATUS",
+        "잘못된 경매 상태입니다.",
+        "Invalid auction status.",
+    ),
+    (
+        "LOW_BID",
+        "입찰 금액이 현재 가격보다 낮습니다.",
+        "The bid amount must be higher than the current price.",
+    ),
+    (
+        "BID_INCREMENT_TOO_SMALL",
+        "입찰 금액이 최소 입찰 단위보다 작습니다.",
+        "The bid amount is below the minimum bid increment.",
+    ),
+    (
+        "INVALID_MAX_BID",
+        "최대 입찰가는 입찰 금액보다 낮을 수 없습니다.",
+        "The maximum bid cannot be lower than the bid amount.",
+    ),
+    (
+        "INVALID_ITEM",
+        "상품 정보가 올바르지 않습니다.",
+        "Invalid item details.",
+    ),
+    (
+        "CANCEL_REASON_REQUIRED",
+        "경매 취소 사유를 입력해야 합니다.",
+        "A cancellation reason is required.",
+    ),
+    (
+        "NOT_ITEM_SELLER",
+        "상품 판매자만 요청할 수 있습니다.",
+        "Only the seller of the item can make this request.",
+    ),
+    (
+        "ADMIN_REQUIRED",
+        "관리자만 요청할 수 있습니다.",
+        "Only administrators can make this request.",
+    ),
+    (
+        "PROXY_BID_NOT_SUPPORTED",
+        "이 경매 방식에서는 자동 입찰을 사용할 수 없습니다.",
+        "Proxy bidding is not available for this auction type.",
+    ),
+    (
+        "BUY_NOW_NOT_SUPPORTED",
+        "이 경매 방식에서는 즉시 구매를 사용할 수 없습니다.",
+        "Buy now is not available for this auction type.",
+    ),
+    (
+        "RETRACTION_NOT_ALLOWED",
+        "입찰을 철회할 수 없습니다.",
+        "The bid cannot be retracted.",
+    ),
+    ("VERSION_CONFLICT", "버전 충돌", "Version conflict"),
+    (
+        "MAX_RETRIES_EXCEEDED",
+        "최대 재시도 횟수 초과",
+        "Maximum retries exceeded",
+    ),
+    (
+        "IDEMPOTENCY_KEY_REUSED",
+        "다른 요청에 사용된 멱등성 키입니다.",
+        "The idempotency key was used for a different request.",
+    ),
+    (
+        "IDEMPOTENCY_KEY_IN_PROGRESS",
+        "같은 멱등성 키로 처리 중인 요청이 있습니다.",
+        "A request with the same idempotency key is in progress.",
+    ),
+    (
+        "ALREADY_REPLAYED",
+        "이미 재처리된 데드레터입니다.",
+        "The dead letter has already been replayed.",
+    ),
+    (
+        "REPLAY_FAILED",
+        "데드레터를 재처리하지 못했습니다.",
+        "Failed to replay the dead letter.",
+    ),
+    ("DATABASE_ERROR", "데이터베이스 오류", "Database error"),
+    ("INTERNAL_ERROR", "내부 오류", "Internal error"),
+    // 오류 상세
+    (
+        "NOT_FOUND.detail",
+        "{resource}을(를) 찾을 수 없습니다: id={id}",
+        "{resource} not found: id={id}",
+    ),
+    (
+        "INVALID_STATUS.detail",
+        "잘못된 경매 상태입니다: {status}",
+        "Invalid auction status: {status}",
+    ),
+    (
+        "LOW_BID.detail",
+        "입찰 금액이 현재 가격보다 낮습니다. (입찰 금액: {bid_amount})",
+        "The bid amount must be higher than the current price. (bid amount: {bid_amount})",
+    ),
+    (
+        "BID_INCREMENT_TOO_SMALL.detail",
+        "입찰 금액이 최소 입찰 단위보다 작습니다. (입찰 금액: {bid_amount}, 다음 입찰 가능 금액: {next_acceptable_amount})",
+        "The bid amount is below the minimum bid increment. (bid amount: {bid_amount}, next acceptable amount: {next_acceptable_amount})",
+    ),
+    (
+        "INVALID_MAX_BID.detail",
+        "최대 입찰가는 입찰 금액보다 낮을 수 없습니다. (입찰 금액: {bid_amount}, 최대 입찰가: {max_bid})",
+        "The maximum bid cannot be lower than the bid amount. (bid amount: {bid_amount}, max bid: {max_bid})",
+    ),
+    (
+        "INVALID_ITEM.detail",
+        "상품 정보가 올바르지 않습니다: {reason}",
+        "Invalid item details: {reason}",
+    ),
+    (
+        "RETRACTION_NOT_ALLOWED.detail",
+        "입찰을 철회할 수 없습니다: {reason}",
+        "The bid cannot be retracted: {reason}",
+    ),
+    (
+        "REPLAY_FAILED.detail",
+        "데드레터를 재처리하지 못했습니다: {reason}",
+        "Failed to replay the dead letter: {reason}",
+    ),
+    // 상품 정보 검증 사유
+    (
+        "item.title_required",
+        "상품명을 입력해야 합니다.",
+        "A title is required.",
+    ),
+    (
+        "item.seller_required",
+        "판매자를 입력해야 합니다.",
+        "A seller is required.",
+    ),
+    (
+        "item.invalid_starting_price",
+        "시작 가격은 0보다 커야 합니다.",
+        "The starting price must be greater than zero.",
+    ),
+    (
+        "item.buy_now_below_starting_price",
+        "즉시 구매 가격은 시작 가격 이상이어야 합니다.",
+        "The buy now price must not be lower than the starting price.",
+    ),
+    (
+        "item.invalid_reserve_price",
+        "최저 낙찰가는 시작 가격 이상, 즉시 구매 가격 이하여야 합니다. (가격 하락식 경매는 시작 가격 이하)",
+        "The reserve price must be between the starting price and the buy now price (at most the starting price for Dutch auctions).",
+    ),
+    (
+        "item.invalid_bid_increments",
+        "입찰 단위는 0보다 커야 합니다.",
+        "Bid increments must be greater than zero.",
+    ),
+    (
+        "item.end_before_start",
+        "종료 시간은 시작 시간 이후여야 합니다.",
+        "The end time must be after the start time.",
+    ),
+    (
+        "item.end_in_past",
+        "종료 시간이 이미 지났습니다.",
+        "The end time has already passed.",
+    ),
+    (
+        "item.invalid_soft_close",
+        "경매 연장 설정은 0 이상이어야 합니다.",
+        "Soft close settings must not be negative.",
+    ),
+    (
+        "item.invalid_auction_type",
+        "경매 방식은 ENGLISH, DUTCH, SEALED_FIRST_PRICE, SEALED_SECOND_PRICE 중 하나여야 합니다.",
+        "The auction type must be one of ENGLISH, DUTCH, SEALED_FIRST_PRICE or SEALED_SECOND_PRICE.",
+    ),
+    (
+        "item.invalid_price_drop",
+        "가격 하락식 경매는 시작 가격보다 작은 가격 하락 금액과 0보다 큰 가격 하락 주기가 필요합니다.",
+        "Dutch auctions require a price drop amount below the starting price and a positive price drop interval.",
+    ),
+    // 입찰 철회 불가 사유
+    (
+        "retraction.no_bid",
+        "철회할 입찰이 없습니다.",
+        "There is no bid to retract.",
+    ),
+    (
+        "retraction.window_expired",
+        "입찰 후 철회 가능 시간이 지났습니다.",
+        "The retraction window for this bid has expired.",
+    ),
+    (
+        "retraction.closing_soon",
+        "경매 종료 직전에는 입찰을 철회할 수 없습니다.",
+        "Bids cannot be retracted close to the end of the auction.",
+    ),
+    (
+        "retraction.sealed_bid",
+        "비공개 입찰은 철회할 수 없습니다. 입찰을 다시 제출하여 변경하세요.",
+        "Sealed bids cannot be retracted. Submit a new bid to replace it.",
+    ),
+    // 리소스 이름
+    ("resource.unknown", "리소스", "Resource"),
+    ("resource.item", "상품", "Item"),
+    ("resource.dead_letter", "데드레터", "Dead letter"),
+    // 처리 결과
+    (
+        "BID_ACCEPTED",
+        "입찰이 성공적으로 처리되었습니다.",
+        "The bid was placed successfully.",
+    ),
+    (
+        "BID_RETRACTED",
+        "입찰이 철회되었습니다.",
+        "The bid was retracted.",
+    ),
+    (
+        "BUY_NOW_EXECUTED",
+        "즉시 구매가 완료되었습니다.",
+        "Buy now executed successfully",
+    ),
+    (
+        "ITEM_LISTED",
+        "상품이 등록되었습니다.",
+        "The item was listed.",
+    ),
+    (
+        "ITEM_UPDATED",
+        "상품 정보가 수정되었습니다.",
+        "The item was updated.",
+    ),
+    (
+        "ITEM_WITHDRAWN",
+        "상품 등록이 철회되었습니다.",
+        "The item was withdrawn.",
+    ),
+    (
+        "AUCTION_CANCELLED",
+        "경매가 취소되었습니다.",
+        "The auction was cancelled.",
+    ),
+    (
+        "SNAPSHOTS_REBUILT",
+        "스냅샷이 재생성되었습니다.",
+        "Snapshots were rebuilt.",
+    ),
+    (
+        "DEAD_LETTER_REPLAYED",
+        "데드레터가 재처리되었습니다.",
+        "The dead letter was replayed.",
+    ),
+];
+
+/// 메시지 조회 (카탈로그에 없는 키는 키 그대로 반환)
+pub(super) fn lookup(key: &'static str, locale: Locale) -> &'static str {
+    MESSAGES
+        .iter()
+        .find(|(k, _, _)| *k == key)
+        .map(|(_, ko, en)| match locale {
+            Locale::Ko => *ko,
+            Locale::En => *en,
+        })
+        .unwrap_or(key)
+}
+
+// endregion: --- Messages
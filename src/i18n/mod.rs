@@ -0,0 +1,104 @@
+/// 다국어 메시지
+/// 사용자에게 보여주는 오류/상태 메시지는 메시지 카탈로그(`messages.rs`)에서 오류 코드(`code`)를 키로 조회
+/// 응답 언어는 `Accept-Language` 헤더로 결정하며, 지원하지 않는 언어거나 헤더가 없으면 한국어로 응답
+// region:    --- Imports
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::http::{header, HeaderMap, HeaderValue};
+use std::convert::Infallible;
+
+mod messages;
+
+// endregion: --- Imports
+
+// region:    --- Locale
+/// 지원 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// 한국어 (기본)
+    #[default]
+    Ko,
+    /// 영어
+    En,
+}
+
+impl Locale {
+    /// 언어 태그로 지원 언어 조회 (기본 언어 태그만 비교, e.g., `en-US` -> En)
+    fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split('-').next().unwrap_or_default();
+        if primary.eq_ignore_ascii_case("ko") {
+            Some(Self::Ko)
+        } else if primary.eq_ignore_ascii_case("en") {
+            Some(Self::En)
+        } else {
+            None
+        }
+    }
+
+    /// `Accept-Language` 헤더 협상
+    /// 품질 값(q)이 높은 순으로 지원 언어를 찾고, 없으면 한국어
+    pub fn negotiate(accept_language: &str) -> Self {
+        let mut candidates: Vec<(f32, usize, &str)> = accept_language
+            .split(',')
+            .enumerate()
+            .filter_map(|(order, range)| {
+                let mut parts = range.trim().split(';');
+                let tag = parts.next()?.trim();
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .and_then(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                (!tag.is_empty() && quality > 0.0).then_some((quality, order, tag))
+            })
+            .collect();
+        // 품질 값 내림차순, 같으면 헤더에 나온 순서
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+        candidates
+            .iter()
+            .find_map(|(_, _, tag)| Self::from_tag(tag))
+            .unwrap_or_default()
+    }
+
+    /// 요청 헤더로부터 언어 결정
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        headers
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(Self::negotiate)
+            .unwrap_or_default()
+    }
+
+    /// 응답 `Content-Language` 헤더 값
+    pub fn content_language(&self) -> HeaderValue {
+        HeaderValue::from_static(match self {
+            Self::Ko => "ko",
+            Self::En => "en",
+        })
+    }
+
+    /// 메시지 카탈로그 조회 (카탈로그에 없는 키는 키 그대로 반환)
+    pub fn message(&self, key: &'static str) -> &'static str {
+        messages::lookup(key, *self)
+    }
+
+    /// 매개변수가 있는 메시지 조회 (`{name}` 자리에 값 치환)
+    pub fn format(&self, key: &'static str, args: &[(&str, String)]) -> String {
+        args.iter()
+            .fold(self.message(key).to_string(), |message, (name, value)| {
+                message.replace(&format!("{{{}}}", name), value)
+            })
+    }
+}
+
+/// 핸들러에서 `Locale`을 추출기로 사용
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Locale {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_headers(&parts.headers))
+    }
+}
+
+// endregion: --- Locale
@@ -0,0 +1,385 @@
+/// 멱등성 키 관리
+/// 클라이언트가 `Idempotency-Key` 헤더와 함께 명령을 보내면, 키를 선점한 뒤 명령을 처리하고 응답을 저장한다.
+/// 같은 키로 다시 요청하면 명령을 다시 실행하지 않고 저장된 응답을 그대로 반환한다.
+/// 키는 `IDEMPOTENCY_KEY_TTL_SECS` 환경 변수로 지정한 시간(기본 24시간)이 지나면 만료된다.
+/// 처리 중에는 선점 임대 시간을 주기적으로 연장하므로, 명령이 버전 충돌 재시도로 길어져도 다른 요청이 키를 다시 선점하지 않는다.
+/// 처리 중 프로세스가 중단된 키는 연장이 멈추므로 선점 임대 시간(30초)이 지나면 새 요청이 다시 선점한다.
+// region:    --- Imports
+use crate::database::DatabaseManager;
+use crate::error::AuctionError;
+use crate::i18n::Locale;
+use axum::body::{to_bytes, Body};
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::{Duration, Utc};
+use sqlx::{FromRow, PgPool};
+use std::future::Future;
+use std::sync::{Arc, OnceLock};
+use tokio::time::interval;
+use tracing::{error, info, warn};
+
+// endregion: --- Imports
+
+// region:    --- Idempotency Key
+/// 멱등성 키 헤더 이름
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// 저장된 응답을 반환했음을 알리는 헤더 이름
+const IDEMPOTENT_REPLAYED_HEADER: &str = "Idempotent-Replayed";
+
+/// 기본 멱등성 키 유효 시간 (초)
+const DEFAULT_TTL_SECS: i64 = 24 * 60 * 60;
+
+/// 응답 저장 전 선점 임대 시간 (초), 지나면 처리 중단으로 보고 새 요청이 다시 선점
+const LOCK_LEASE_SECS: i64 = 30;
+
+/// 처리 중 선점 임대 시간 연장 주기 (초), 연장이 한두 번 실패해도 임대 시간이 지나지 않도록 임대 시간보다 충분히 짧게 설정
+const LOCK_RENEW_INTERVAL_SECS: u64 = 10;
+
+/// 저장할 응답 본문 최대 크기
+const MAX_RESPONSE_BYTES: usize = 1024 * 1024;
+
+static TTL_SECS: OnceLock<i64> = OnceLock::new();
+
+/// 멱등성 키 유효 시간 조회 (`IDEMPOTENCY_KEY_TTL_SECS` 환경 변수)
+pub fn ttl_secs() -> i64 {
+    *TTL_SECS.get_or_init(|| {
+        std::env::var("IDEMPOTENCY_KEY_TTL_SECS")
+            .ok()
+            .and_then(|value| value.parse::<i64>().ok())
+            .filter(|ttl| *ttl > 0)
+            .unwrap_or(DEFAULT_TTL_SECS)
+    })
+}
+
+/// 저장된 멱등성 키
+#[derive(Debug, FromRow)]
+struct IdempotencyRecord {
+    endpoint: String,
+    request: serde_json::Value,
+    status_code: Option<i32>,
+    content_type: Option<String>,
+    response_body: Option<String>,
+}
+
+/// 요청 헤더에서 멱등성 키 추출
+pub fn idempotency_key(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// 멱등성 키 기준으로 명령 처리
+/// 1. 키가 없으면 그대로 처리
+/// 2. 처음 보는 키면 선점 후 처리하고 응답 저장 (오류 응답이나 처리 중 패닉은 저장하지 않고 키 해제)
+/// 3. 처리가 끝난 키면 저장된 응답 반환
+/// 4. 처리 중인 키거나 다른 요청에 사용된 키면 오류 반환 (요청 언어로 응답)
+pub async fn run<F, Fut>(
+    db_manager: &DatabaseManager,
+    locale: Locale,
+    key: Option<String>,
+    endpoint: &str,
+    request: serde_json::Value,
+    handler: F,
+) -> Response
+where
+    F: FnOnce(Option<String>) -> Fut,
+    Fut: Future<Output = Response>,
+{
+    let Some(key) = key else {
+        return handler(None).await;
+    };
+
+    match reserve(db_manager, &key, endpoint, &request).await {
+        Ok(None) => {}
+        Ok(Some(record)) => return replay(locale, &key, endpoint, &request, record),
+        Err(e) => {
+            error!("{:<12} --> 멱등성 키 선점 실패: {}", "Idempotency", e);
+            return AuctionError::Database(e).to_response(locale);
+        }
+    }
+
+    // 처리 중 패닉이나 요청 취소로 응답을 저장하지 못하면 선점 해제
+    let reservation = Reservation::new(db_manager.pool().clone(), key.clone());
+    // 처리가 끝날 때까지 임대 시간 연장 (처리 중인 키를 다른 요청이 다시 선점하지 않도록)
+    let response = tokio::select! {
+        response = handler(Some(key.clone())) => response,
+        _ = renew_lease(db_manager.pool(), &key) => unreachable!("임대 시간 연장은 종료되지 않음"),
+    };
+
+    // 오류 응답은 명령이 반영되지 않았으므로 같은 키로 재시도할 수 있도록 키 해제
+    if !response.status().is_success() {
+        reservation.release().await;
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_RESPONSE_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            error!("{:<12} --> 응답 본문 읽기 실패: {}", "Idempotency", e);
+            reservation.release().await;
+            return AuctionError::Internal(e.to_string()).to_response(locale);
+        }
+    };
+    reservation.keep();
+    let content_type = parts
+        .headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok());
+    if let Err(e) = complete(
+        db_manager,
+        &key,
+        parts.status,
+        content_type,
+        &String::from_utf8_lossy(&bytes),
+    )
+    .await
+    {
+        warn!("{:<12} --> 멱등성 응답 저장 실패: {}", "Idempotency", e);
+    }
+
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// 멱등성 키 선점
+/// 선점에 성공하면 None, 이미 저장된 키가 있으면 저장된 내용 반환
+/// 만료된 키와 임대 시간이 지나도록 응답이 저장되지 않은 키는 삭제 후 다시 선점
+async fn reserve(
+    db_manager: &DatabaseManager,
+    key: &str,
+    endpoint: &str,
+    request: &serde_json::Value,
+) -> Result<Option<IdempotencyRecord>, String> {
+    let now = Utc::now();
+    let key = key.to_string();
+    let endpoint = endpoint.to_string();
+    let request = request.clone();
+    db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                let deleted = sqlx::query_scalar::<_, Option<i32>>(
+                    "DELETE FROM idempotency_keys
+                    WHERE idempotency_key = $1
+                    AND (expires_at <= $2 OR (status_code IS NULL AND locked_at <= $3))
+                    RETURNING status_code",
+                )
+                .bind(&key)
+                .bind(now)
+                .bind(now - Duration::seconds(LOCK_LEASE_SECS))
+                .fetch_optional(&mut **tx)
+                .await?;
+                // 응답이 없는 키를 삭제했으면 임대 시간이 지난 처리 중 키
+                if deleted == Some(None) {
+                    warn!(
+                        "{:<12} --> 임대 시간이 지난 멱등성 키 다시 선점: key={}",
+                        "Idempotency", key
+                    );
+                }
+
+                let reserved = sqlx::query(
+                    "INSERT INTO idempotency_keys (idempotency_key, endpoint, request, created_at, expires_at, locked_at)
+                    VALUES ($1, $2, $3, $4, $5, $4)
+                    ON CONFLICT (idempotency_key) DO NOTHING",
+                )
+                .bind(&key)
+                .bind(&endpoint)
+                .bind(&request)
+                .bind(now)
+                .bind(now + Duration::seconds(ttl_secs()))
+                .execute(&mut **tx)
+                .await?
+                .rows_affected()
+                    == 1;
+                if reserved {
+                    return Ok(None);
+                }
+
+                sqlx::query_as::<_, IdempotencyRecord>(
+                    "SELECT endpoint, request, status_code, content_type, response_body
+                    FROM idempotency_keys
+                    WHERE idempotency_key = $1",
+                )
+                .bind(&key)
+                .fetch_optional(&mut **tx)
+                .await
+            })
+        })
+        .await
+        .map_err(|e: sqlx::Error| e.to_string())
+}
+
+/// 처리 중인 키의 선점 임대 시간 연장
+/// 처리가 끝나 취소될 때까지 반복하며, 연장에 실패하면 다음 주기에 다시 연장
+async fn renew_lease(pool: &PgPool, key: &str) {
+    let mut interval = interval(tokio::time::Duration::from_secs(LOCK_RENEW_INTERVAL_SECS));
+    // 첫 주기는 선점 직후이므로 건너뜀
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        match sqlx::query(
+            "UPDATE idempotency_keys SET locked_at = $1
+            WHERE idempotency_key = $2 AND status_code IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(key)
+        .execute(pool)
+        .await
+        {
+            Ok(result) if result.rows_affected() == 0 => warn!(
+                "{:<12} --> 연장할 멱등성 키 선점 없음: key={}",
+                "Idempotency", key
+            ),
+            Ok(_) => {}
+            Err(e) => warn!(
+                "{:<12} --> 멱등성 키 임대 시간 연장 실패: key={}, {}",
+                "Idempotency", key, e
+            ),
+        }
+    }
+}
+
+/// 저장된 응답 반환 (저장된 응답은 처음 요청한 언어 그대로 반환)
+fn replay(
+    locale: Locale,
+    key: &str,
+    endpoint: &str,
+    request: &serde_json::Value,
+    record: IdempotencyRecord,
+) -> Response {
+    if record.endpoint != endpoint || &record.request != request {
+        return AuctionError::IdempotencyKeyReused.to_response(locale);
+    }
+
+    let (Some(status_code), Some(response_body)) = (record.status_code, record.response_body)
+    else {
+        return AuctionError::IdempotencyKeyInProgress.to_response(locale);
+    };
+
+    info!(
+        "{:<12} --> 저장된 응답 반환: key={}, status={}",
+        "Idempotency", key, status_code
+    );
+    let status = StatusCode::from_u16(status_code as u16).unwrap_or(StatusCode::OK);
+    let mut response = (status, response_body).into_response();
+    if let Some(content_type) = record
+        .content_type
+        .and_then(|value| HeaderValue::from_str(&value).ok())
+    {
+        response
+            .headers_mut()
+            .insert(header::CONTENT_TYPE, content_type);
+    }
+    response
+        .headers_mut()
+        .insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+/// 처리 결과 저장
+async fn complete(
+    db_manager: &DatabaseManager,
+    key: &str,
+    status: StatusCode,
+    content_type: Option<&str>,
+    response_body: &str,
+) -> Result<(), String> {
+    sqlx::query(
+        "UPDATE idempotency_keys
+        SET status_code = $1, content_type = $2, response_body = $3, locked_at = NULL
+        WHERE idempotency_key = $4",
+    )
+    .bind(status.as_u16() as i32)
+    .bind(content_type)
+    .bind(response_body)
+    .bind(key)
+    .execute(db_manager.pool())
+    .await
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 선점한 멱등성 키
+/// 응답 저장 전에 해제되지 않고 버려지면 (처리 중 패닉, 요청 취소) 백그라운드에서 키 해제
+struct Reservation {
+    pool: PgPool,
+    key: Option<String>,
+}
+
+impl Reservation {
+    fn new(pool: PgPool, key: String) -> Self {
+        Self {
+            pool,
+            key: Some(key),
+        }
+    }
+
+    /// 응답을 저장할 것이므로 선점 유지
+    fn keep(mut self) {
+        self.key = None;
+    }
+
+    /// 키 해제
+    async fn release(mut self) {
+        if let Some(key) = self.key.take() {
+            release(&self.pool, &key).await;
+        }
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        let Some(key) = self.key.take() else {
+            return;
+        };
+        warn!(
+            "{:<12} --> 응답 저장 전 처리 중단, 멱등성 키 해제: key={}",
+            "Idempotency", key
+        );
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let pool = self.pool.clone();
+            handle.spawn(async move { release(&pool, &key).await });
+        }
+    }
+}
+
+/// 멱등성 키 해제
+async fn release(pool: &PgPool, key: &str) {
+    if let Err(e) = sqlx::query("DELETE FROM idempotency_keys WHERE idempotency_key = $1")
+        .bind(key)
+        .execute(pool)
+        .await
+    {
+        warn!("{:<12} --> 멱등성 키 해제 실패: {}", "Idempotency", e);
+    }
+}
+
+/// 만료된 멱등성 키 정리 시작 (1분마다 실행)
+pub fn start_cleanup(db_manager: Arc<DatabaseManager>) {
+    tokio::spawn(async move {
+        let mut interval = interval(tokio::time::Duration::from_secs(60));
+        loop {
+            interval.tick().await;
+            match sqlx::query("DELETE FROM idempotency_keys WHERE expires_at <= $1")
+                .bind(Utc::now())
+                .execute(db_manager.pool())
+                .await
+            {
+                Ok(result) if result.rows_affected() > 0 => info!(
+                    "{:<12} --> 만료된 멱등성 키 삭제: {}건",
+                    "Idempotency",
+                    result.rows_affected()
+                ),
+                Ok(_) => {}
+                Err(e) => error!(
+                    "{:<12} --> 만료된 멱등성 키 삭제 실패: {}",
+                    "Idempotency", e
+                ),
+            }
+        }
+    });
+}
+
+// endregion: --- Idempotency Key
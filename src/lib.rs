@@ -1,8 +1,13 @@
 pub mod auction;
 pub mod bidding;
 pub mod database;
+pub mod error;
 pub mod event_store;
 pub mod handlers;
+pub mod i18n;
+pub mod idempotency;
 pub mod message_broker;
+pub mod outbox;
 pub mod query;
+pub mod realtime;
 pub mod scheduler;
@@ -1,30 +1,20 @@
 // region:    --- Imports
-use crate::database::DatabaseManager;
-use crate::event_store::EventConsumer;
+use auction_service::database::DatabaseManager;
+use auction_service::event_store::EventConsumer;
+use auction_service::realtime::AuctionUpdates;
+use auction_service::{handlers, idempotency, message_broker, outbox, scheduler};
 use axum::{
-    extract::DefaultBodyLimit,
+    extract::{DefaultBodyLimit, Extension},
+    middleware,
     routing::{get, post},
     Router,
 };
-use message_broker::KafkaManager;
 use std::sync::Arc;
 use tokio::net::TcpListener;
 use tower_http::cors::{Any, CorsLayer};
 use tracing::{error, info};
 // endregion: --- Imports
 
-// region:    --- Modules
-mod auction;
-mod bidding;
-mod database;
-mod event_store;
-mod handlers;
-mod message_broker;
-mod query;
-mod scheduler;
-
-// endregion: --- Modules
-
 // region:    --- Main
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -45,25 +35,42 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     info!("{:<12} --> 데이터베이스 초기화 성공", "Main");
 
-    // Kafka 매니저 생성 및 초기화
-    let kafka_manager = Arc::new(KafkaManager::new());
-    if let Err(e) = kafka_manager.initialize().await {
-        error!("{:<12} --> Kafka 초기화 실패: {:?}", "Main", e);
-        return Err(e.into());
-    }
-    info!("{:<12} --> Kafka 초기화 성공", "Main");
+    // 메시지 브로커 생성 및 초기화 (MESSAGE_BROKER: kafka(기본), memory)
+    let message_broker = match message_broker::from_env().await {
+        Ok(message_broker) => message_broker,
+        Err(e) => {
+            error!("{:<12} --> 메시지 브로커 초기화 실패: {:?}", "Main", e);
+            return Err(e.into());
+        }
+    };
+    info!("{:<12} --> 메시지 브로커 초기화 성공", "Main");
 
     // 토픽 생성
-    kafka_manager.create_topic("events", 5, 1).await?;
+    message_broker.create_topic("events", 5, 1).await?;
+    message_broker
+        .create_topic(&message_broker::dead_letter_topic(), 1, 1)
+        .await?;
 
-    // 이벤트 소싱 시작
-    let event_consumer = EventConsumer::new(Arc::clone(&db_manager), kafka_manager.get_consumer());
+    // 이벤트 소싱 시작 (조회 모델에 반영한 이벤트는 실시간 구독자에게 알림)
+    let updates = Arc::new(AuctionUpdates::new());
+    let event_consumer = EventConsumer::new(
+        Arc::clone(&db_manager),
+        Arc::clone(&message_broker),
+        Arc::clone(&updates),
+    );
     tokio::spawn(async move {
         event_consumer.start().await;
     });
 
+    // 아웃박스 릴레이 시작 (outbox에 기록된 이벤트를 메시지 브로커로 발행)
+    let outbox_relay = outbox::OutboxRelay::new(Arc::clone(&db_manager), message_broker);
+    outbox_relay.start().await;
+
+    // 만료된 멱등성 키 정리 시작
+    idempotency::start_cleanup(Arc::clone(&db_manager));
+
     // 가상의 상품 (상태) 관리 마이크로 서비스
-    let scheduler = scheduler::AuctionScheduler::new(db_manager.get_pool());
+    let scheduler = scheduler::AuctionScheduler::new(Arc::clone(&db_manager));
     scheduler.start().await;
 
     // 테스트 페이지를 위한 cors 설정
@@ -72,9 +79,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .allow_methods(Any)
         .allow_headers(Any);
 
+    // 관리자 라우터 설정 (관리자 토큰 확인)
+    let routes_admin = Router::new()
+        .route(
+            "/admin/snapshots/rebuild",
+            post(handlers::handle_rebuild_snapshots),
+        )
+        .route(
+            "/admin/dead-letters",
+            get(handlers::handle_get_dead_letters),
+        )
+        .route(
+            "/admin/dead-letters/:id/replay",
+            post(handlers::handle_replay_dead_letter),
+        )
+        .route_layer(middleware::from_fn(handlers::require_admin));
+
     // 라우터 설정
     let routes_all = Router::new()
         .route("/bid", post(handlers::handle_bid))
+        .route("/bid/retract", post(handlers::handle_retract))
         .route("/buy-now", post(handlers::handle_buy_now))
         .route("/auction/:id", get(handlers::handle_get_auction_state))
         .route(
@@ -82,12 +106,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             get(handlers::handle_get_highest_bid),
         )
         .route("/auction/:id/bids", get(handlers::handle_get_bid_history))
-        .route("/items", get(handlers::handle_get_items))
-        .route("/items/:id", get(handlers::handle_get_item))
+        .route("/auction/:id/cancel", post(handlers::handle_cancel_auction))
+        .route("/auction/:id/stream", get(handlers::handle_stream_auction))
+        .route(
+            "/items",
+            get(handlers::handle_get_items).post(handlers::handle_list_item),
+        )
+        .route("/items/stream", get(handlers::handle_stream_items))
+        .route(
+            "/items/:id",
+            get(handlers::handle_get_item)
+                .patch(handlers::handle_update_item)
+                .delete(handlers::handle_withdraw_item),
+        )
         .route("/items/:id/bids", get(handlers::handle_get_item_bids))
+        .route("/ws/auction/:id", get(handlers::handle_subscribe_auction))
+        .merge(routes_admin)
+        .layer(Extension(updates))
         .layer(cors)
         .layer(DefaultBodyLimit::max(1024 * 1024 * 20)) // 동시성을 위한 바디 사이즈 10배 증가(20MB)
-        .with_state((db_manager, kafka_manager.get_producer()));
+        .with_state(db_manager);
 
     // 리스너 생성(로컬 호스트의 3000번 포트를 사용)
     let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
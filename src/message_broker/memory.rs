@@ -0,0 +1,144 @@
+/// 인메모리 메시지 브로커
+/// 토픽별 tokio 채널로 메시지를 전달하며, Kafka 없이 단일 프로세스에서 서비스를 실행할 때 사용
+/// 토픽별 구독자는 하나(단일 컨슈머 그룹)이며, 구독 전에 발행된 메시지는 채널에 보관했다가 전달
+/// 토픽 채널 용량은 `TOPIC_CAPACITY`이며, 구독자가 있으면 소비할 때까지 발행을 대기하고
+/// 구독자가 없는 토픽은 용량을 넘으면 발행 실패 (아웃박스 릴레이가 재시도)
+/// 단, 데드레터 토픽은 dead_letters 테이블에도 기록되므로 용량을 넘는 메시지를 버림
+// region:    --- Imports
+use super::{dead_letter_topic, BrokerMessage, MessageBroker, Subscription};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tokio::sync::mpsc::{self, error::TrySendError, Receiver, Sender};
+use tracing::{info, warn};
+
+// endregion: --- Imports
+
+// region:    --- In-Memory Broker
+/// 토픽 채널 용량
+const TOPIC_CAPACITY: usize = 10_000;
+
+/// 토픽 채널
+struct Topic {
+    sender: Sender<BrokerMessage>,
+    /// 구독 전까지 보관하는 수신 채널 (None: 구독 중)
+    receiver: Option<Receiver<BrokerMessage>>,
+    next_offset: i64,
+}
+
+impl Topic {
+    fn new() -> Self {
+        let (sender, receiver) = mpsc::channel(TOPIC_CAPACITY);
+        Self {
+            sender,
+            receiver: Some(receiver),
+            next_offset: 0,
+        }
+    }
+}
+
+/// 인메모리 메시지 브로커
+#[derive(Default)]
+pub struct InMemoryBroker {
+    topics: Mutex<HashMap<String, Topic>>,
+}
+
+impl InMemoryBroker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl MessageBroker for InMemoryBroker {
+    async fn create_topic(
+        &self,
+        topic_name: &str,
+        _num_partitions: i32,
+        _replication_factor: i32,
+    ) -> Result<(), String> {
+        self.topics
+            .lock()
+            .map_err(|e| e.to_string())?
+            .entry(topic_name.to_string())
+            .or_insert_with(Topic::new);
+        info!("{:<12} --> 토픽 생성: {}", "InMemory", topic_name);
+        Ok(())
+    }
+
+    async fn publish(
+        &self,
+        topic: &str,
+        key: &str,
+        payload: &str,
+        _headers: &[(&str, &str)],
+    ) -> Result<(), String> {
+        info!(
+            "{:<12} --> 메시지 전송: topic={}, key={}",
+            "InMemory", topic, key
+        );
+        let (sender, subscribed, message) = {
+            let mut topics = self.topics.lock().map_err(|e| e.to_string())?;
+            let channel = topics.entry(topic.to_string()).or_insert_with(Topic::new);
+            let message = BrokerMessage {
+                topic: topic.to_string(),
+                partition: 0,
+                offset: channel.next_offset,
+                key: Some(key.to_string()),
+                payload: Some(payload.as_bytes().to_vec()),
+            };
+            channel.next_offset += 1;
+            (channel.sender.clone(), channel.receiver.is_none(), message)
+        };
+
+        // 구독자가 있으면 채널에 자리가 생길 때까지 대기
+        if subscribed {
+            return sender.send(message).await.map_err(|e| e.to_string());
+        }
+        match sender.try_send(message) {
+            Err(TrySendError::Full(message)) if topic == dead_letter_topic() => {
+                warn!(
+                    "{:<12} --> 데드레터 토픽의 채널이 가득 차 메시지 버림: topic={}, offset={}",
+                    "InMemory", topic, message.offset
+                );
+                Ok(())
+            }
+            Err(TrySendError::Full(_)) => Err(format!(
+                "구독자가 없는 토픽의 채널이 가득 찼습니다: {}",
+                topic
+            )),
+            result => result.map_err(|e| e.to_string()),
+        }
+    }
+
+    async fn subscribe(&self, topic: &str) -> Result<Box<dyn Subscription>, String> {
+        let mut topics = self.topics.lock().map_err(|e| e.to_string())?;
+        let receiver = topics
+            .entry(topic.to_string())
+            .or_insert_with(Topic::new)
+            .receiver
+            .take()
+            .ok_or_else(|| format!("이미 구독 중인 토픽입니다: {}", topic))?;
+        Ok(Box::new(InMemorySubscription { receiver }))
+    }
+}
+
+/// 인메모리 토픽 구독
+struct InMemorySubscription {
+    receiver: Receiver<BrokerMessage>,
+}
+
+#[async_trait]
+impl Subscription for InMemorySubscription {
+    /// 브로커가 삭제되어 채널이 닫히면 None
+    async fn recv(&mut self) -> Result<Option<BrokerMessage>, String> {
+        Ok(self.receiver.recv().await)
+    }
+
+    /// 메시지는 수신 시 채널에서 제거되므로 커밋할 오프셋 없음
+    fn commit(&self, _message: &BrokerMessage) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+// endregion: --- In-Memory Broker
@@ -1,261 +1,285 @@
+/// 메시지 브로커
+/// 이벤트 발행 및 구독을 `MessageBroker` 트레이트로 추상화
+/// 1. Kafka: 운영 환경 (스케일 아웃)
+/// 2. 인메모리(tokio 채널): 단일 프로세스 실행 및 테스트 (PostgreSQL만 필요)
 // region:    --- Imports
 use crate::event_store::Event;
-use rdkafka::admin::{AdminClient, AdminOptions, NewTopic, TopicReplication};
-use rdkafka::client::DefaultClientContext;
-use rdkafka::consumer::{Consumer, StreamConsumer};
-use rdkafka::message::Message;
-use rdkafka::producer::{FutureProducer, FutureRecord};
-use rdkafka::ClientConfig;
+use async_trait::async_trait;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time;
 use tracing::{debug, error, info, warn};
 
-// endregion: --- Imports
-
-// region:    --- Kafka Producer
-#[derive(Clone)]
-pub struct KafkaProducer {
-    producer: Arc<FutureProducer>,
-}
-
-/// KafkaProducer 구현
-impl KafkaProducer {
-    pub fn new(brokers: &str) -> Self {
-        let producer: FutureProducer = ClientConfig::new()
-            .set("bootstrap.servers", brokers)
-            .set("message.timeout.ms", "5000")
-            .create()
-            .expect("Producer creation error");
-
-        KafkaProducer {
-            producer: Arc::new(producer),
-        }
-    }
-
-    /// 메시지 전송
-    pub async fn send_message(&self, topic: &str, key: &str, value: &str) -> Result<(), String> {
-        info!(
-            "{:<12} --> Kafka 메시지 전송: topic={}, key={}",
-            "Producer", topic, key
-        );
-        let record = FutureRecord::to(topic).key(key).payload(value);
+pub mod kafka;
+pub mod memory;
 
-        self.producer
-            .send(record, std::time::Duration::from_secs(0))
-            .await
-            .map_err(|(e, _)| format!("Error sending message: {:?}", e))?;
+pub use kafka::KafkaManager;
+pub use memory::InMemoryBroker;
 
-        Ok(())
-    }
-}
-
-// endregion: --- Kafka Producer
+// endregion: --- Imports
 
-// region:    --- Kafka Consumer
-pub struct KafkaConsumer {
-    consumer: Arc<StreamConsumer>,
+// region:    --- Message Broker Trait
+/// 브로커로부터 수신한 메시지
+#[derive(Debug, Clone)]
+pub struct BrokerMessage {
+    pub topic: String,
+    pub partition: i32,
+    pub offset: i64,
+    pub key: Option<String>,
+    pub payload: Option<Vec<u8>>,
 }
 
-/// KafkaConsumer 구현
-impl KafkaConsumer {
-    pub fn new(brokers: &str, group_id: &str) -> Self {
-        let consumer: StreamConsumer = ClientConfig::new()
-            .set("bootstrap.servers", brokers)
-            .set("group.id", group_id)
-            .set("enable.auto.commit", "true")
-            .set("auto.offset.reset", "earliest")
-            .set("session.timeout.ms", "6000")
-            .set("fetch.max.bytes", "5242880")
-            .set("allow.auto.create.topics", "true")
-            .create()
-            .expect("Consumer creation failed");
-
-        KafkaConsumer {
-            consumer: Arc::new(consumer),
-        }
-    }
+/// 메시지 브로커 트레이트
+#[async_trait]
+pub trait MessageBroker: Send + Sync {
+    /// 토픽 생성
+    async fn create_topic(
+        &self,
+        topic_name: &str,
+        num_partitions: i32,
+        replication_factor: i32,
+    ) -> Result<(), String>;
 
-    /// 이벤트 소싱
-    pub async fn consume_events<F, Fut>(
+    /// 메시지 발행 (같은 키의 메시지는 발행 순서대로 소비)
+    async fn publish(
         &self,
         topic: &str,
-        handler: F,
-    ) -> Result<(), Box<dyn std::error::Error>>
-    where
-        F: Fn(Event) -> Fut + Send + 'static,
-        Fut: std::future::Future<Output = Result<(), Box<dyn std::error::Error>>> + Send + 'static,
-    {
-        info!(
-            "{:<12} --> Kafka 이벤트 소싱 시작: topic={}",
-            "Consumer", topic
-        );
-        self.consumer.subscribe(&[topic])?;
-
-        loop {
-            match self.consumer.recv().await {
-                Ok(message) => {
-                    info!(
-                        "{:<12} --> 메시지 수신: topic={}, partition={}, offset={}",
-                        "Consumer",
-                        message.topic(),
-                        message.partition(),
-                        message.offset()
-                    );
+        key: &str,
+        payload: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<(), String>;
 
-                    if let Some(payload) = message.payload() {
-                        match serde_json::from_slice::<Event>(payload) {
-                            Ok(event) => {
-                                debug!("{:<12} --> deserialize 성공: {:?}", "Consumer", event);
-
-                                if let Err(e) = handler(event).await {
-                                    error!(
-                                        "{:<12} --> Kafka 이벤트 처리 오류: {:?}",
-                                        "Consumer", e
-                                    );
-                                } else {
-                                    info!("{:<12} --> Kafka 이벤트 처리 성공", "Consumer");
-                                }
-                            }
-                            Err(e) => error!("{:<12} --> deserialize 오류: {:?}", "Consumer", e),
-                        }
-                    } else {
-                        warn!("{:<12} --> 빈 페이로드 수신", "Consumer");
-                    }
-                }
-                Err(e) => error!("{:<12} --> 메시지 수신 오류: {:?}", "Consumer", e),
-            }
-        }
-    }
+    /// 토픽 구독
+    async fn subscribe(&self, topic: &str) -> Result<Box<dyn Subscription>, String>;
 }
 
-// endregion: --- Kafka Consumer
+/// 토픽 구독 트레이트
+#[async_trait]
+pub trait Subscription: Send {
+    /// 다음 메시지 수신 (구독이 종료되어 더 수신할 메시지가 없으면 None)
+    async fn recv(&mut self) -> Result<Option<BrokerMessage>, String>;
 
-// region:    --- Kafka Manager
-pub struct KafkaManager {
-    producer: Arc<KafkaProducer>,
-    consumer: Arc<KafkaConsumer>,
-    brokers: String,
+    /// 처리 완료한 메시지 오프셋 커밋
+    fn commit(&self, message: &BrokerMessage) -> Result<(), String>;
 }
 
-impl Default for KafkaManager {
-    fn default() -> Self {
-        Self::new()
+/// 설정에 따른 메시지 브로커 생성 (`MESSAGE_BROKER` 환경 변수: kafka(기본), memory)
+pub async fn from_env() -> Result<Arc<dyn MessageBroker>, String> {
+    match std::env::var("MESSAGE_BROKER").as_deref() {
+        Ok("memory") => {
+            info!("{:<12} --> 인메모리 메시지 브로커 사용", "Broker");
+            Ok(Arc::new(InMemoryBroker::new()))
+        }
+        _ => {
+            let kafka_manager = KafkaManager::new();
+            kafka_manager.initialize().await?;
+            info!("{:<12} --> Kafka 메시지 브로커 사용", "Broker");
+            Ok(Arc::new(kafka_manager))
+        }
     }
 }
 
-/// KafkaManager 구현
-impl KafkaManager {
-    pub fn new() -> Self {
-        let brokers =
-            std::env::var("KAFKA_BROKERS").unwrap_or_else(|_| "localhost:9092".to_string());
-        let group_id = "events-group".to_string();
+// endregion: --- Message Broker Trait
 
-        let producer = Arc::new(KafkaProducer::new(&brokers));
-        let consumer = Arc::new(KafkaConsumer::new(&brokers, &group_id));
+// region:    --- Event Consumption
+/// 기본 데드레터 토픽
+const DEFAULT_DEAD_LETTER_TOPIC: &str = "events-dead-letter";
 
-        KafkaManager {
-            producer,
-            consumer,
-            brokers,
-        }
-    }
+/// 기본 이벤트 처리 재시도 횟수
+const DEFAULT_MAX_RETRIES: u32 = 3;
 
-    /// 프로듀서 반환
-    pub fn get_producer(&self) -> Arc<KafkaProducer> {
-        Arc::clone(&self.producer)
-    }
+/// 데드레터 전송 재시도 최대 대기 시간
+const MAX_DEAD_LETTER_BACKOFF: Duration = Duration::from_secs(30);
 
-    /// 컨슈머 반환
-    pub fn get_consumer(&self) -> Arc<KafkaConsumer> {
-        Arc::clone(&self.consumer)
-    }
-
-    /// 초기화 메시지 전송
-    pub async fn send_init_message(&self) -> Result<(), String> {
-        info!("{:<12} --> Kafka 초기화 메시지 전송", "Manager");
-        self.producer
-            .send_message("init-topic", "init-key", "init-message")
-            .await
-    }
+/// 데드레터 토픽 조회 (`DEAD_LETTER_TOPIC` 환경 변수)
+pub fn dead_letter_topic() -> String {
+    std::env::var("DEAD_LETTER_TOPIC").unwrap_or_else(|_| DEFAULT_DEAD_LETTER_TOPIC.to_string())
+}
 
-    /// Kafka 초기화
-    pub async fn initialize(&self) -> Result<(), String> {
-        info!("{:<12} --> Kafka 초기화 시작", "Manager");
+/// 이벤트 처리 재시도 횟수 조회 (`EVENT_MAX_RETRIES` 환경 변수)
+fn max_retries() -> u32 {
+    std::env::var("EVENT_MAX_RETRIES")
+        .ok()
+        .and_then(|value| value.parse::<u32>().ok())
+        .unwrap_or(DEFAULT_MAX_RETRIES)
+}
 
-        // 초기화 토픽 구
-        self.consumer
-            .consumer
-            .subscribe(&["init-topic"])
-            .map_err(|e| e.to_string())?;
+/// 처리하지 못해 데드레터 토픽으로 보낸 메시지
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    pub topic: String,
+    pub partition: i32,
+    pub offset: i64,
+    pub message_key: Option<String>,
+    pub payload: String,
+    pub error: String,
+    pub retry_count: u32,
+}
 
-        // 초기화 메시지 전송
-        self.send_init_message().await?;
+/// 이벤트 소싱
+/// 처리에 실패한 메시지는 재시도(`EVENT_MAX_RETRIES`, 기본 3회) 후 데드레터 토픽으로 전송
+/// 처리에 성공하거나 데드레터 토픽으로 보낸 메시지만 오프셋 커밋
+/// 데드레터 전송에 실패하면 성공할 때까지 재시도하며, 다음 메시지는 소비하지 않음 (다음 메시지의 커밋으로 건너뛰지 않도록)
+/// 구독이 종료되면 반환
+pub async fn consume_events<F, Fut, D, DFut>(
+    broker: &dyn MessageBroker,
+    topic: &str,
+    handler: F,
+    dead_letter_handler: D,
+) -> Result<(), String>
+where
+    F: Fn(Event) -> Fut + Send + 'static,
+    Fut: std::future::Future<Output = Result<(), Box<dyn std::error::Error>>> + Send + 'static,
+    D: Fn(DeadLetter) -> DFut + Send + 'static,
+    DFut: std::future::Future<Output = ()> + Send + 'static,
+{
+    info!("{:<12} --> 이벤트 소싱 시작: topic={}", "Consumer", topic);
+    let mut subscription = broker.subscribe(topic).await?;
+    let dead_letter_topic = dead_letter_topic();
+    let max_retries = max_retries();
+
+    loop {
+        let message = match subscription.recv().await {
+            Ok(Some(message)) => message,
+            Ok(None) => {
+                warn!("{:<12} --> 구독 종료: topic={}", "Consumer", topic);
+                return Ok(());
+            }
+            Err(e) => {
+                error!("{:<12} --> 메시지 수신 오류: {:?}", "Consumer", e);
+                continue;
+            }
+        };
+        info!(
+            "{:<12} --> 메시지 수신: topic={}, partition={}, offset={}",
+            "Consumer", message.topic, message.partition, message.offset
+        );
 
-        // 초기화 메시지 수신 대기
-        let mut attempts = 0;
-        let max_attempts = 10;
-        while attempts < max_attempts {
-            match time::timeout(Duration::from_secs(1), self.consumer.consumer.recv()).await {
-                Ok(Ok(message)) => {
-                    if let Some(payload) = message.payload() {
-                        if payload == b"init-message" {
-                            info!("{:<12} --> Kafka 초기화 메시지 수신 확인", "Manager");
-                            return Ok(());
-                        }
-                    }
+        let result = match &message.payload {
+            Some(payload) => match serde_json::from_slice::<Event>(payload) {
+                Ok(event) => {
+                    debug!("{:<12} --> deserialize 성공: {:?}", "Consumer", event);
+                    handle_with_retry(&handler, event, max_retries).await
                 }
-                Ok(Err(e)) => error!(
-                    "{:<12} --> Kafka 초기화 메시지 수신 오류: {:?}",
-                    "Manager", e
-                ),
-                Err(_) => {
-                    attempts += 1;
-                    warn!(
-                        "{:<12} --> Kafka 초기화 메시지 수신 대기 중... (시도: {}/{})",
-                        "Manager", attempts, max_attempts
-                    );
+                Err(e) => {
+                    error!("{:<12} --> deserialize 오류: {:?}", "Consumer", e);
+                    Err((format!("deserialize 오류: {}", e), 0))
                 }
+            },
+            None => {
+                warn!("{:<12} --> 빈 페이로드 수신", "Consumer");
+                Err(("빈 페이로드".to_string(), 0))
             }
-        }
+        };
 
-        Err("Kafka 초기화 메시지 수신 실패".to_string())
+        match result {
+            Ok(()) => {
+                info!("{:<12} --> 이벤트 처리 성공", "Consumer");
+                commit(subscription.as_ref(), &message);
+            }
+            Err((error, retry_count)) => {
+                let dead_letter = DeadLetter {
+                    topic: message.topic.clone(),
+                    partition: message.partition,
+                    offset: message.offset,
+                    message_key: message.key.clone(),
+                    payload: message
+                        .payload
+                        .as_deref()
+                        .map(|payload| String::from_utf8_lossy(payload).to_string())
+                        .unwrap_or_default(),
+                    error,
+                    retry_count,
+                };
+                send_dead_letter_with_retry(broker, &dead_letter_topic, &dead_letter).await;
+                dead_letter_handler(dead_letter).await;
+                commit(subscription.as_ref(), &message);
+            }
+        }
     }
+}
 
-    /// 토픽 생성
-    pub async fn create_topic(
-        &self,
-        topic_name: &str,
-        num_partitions: i32,
-        replication_factor: i32,
-    ) -> Result<(), String> {
-        info!("{:<12} --> Kafka 토픽 생성 시작: {}", "Manager", topic_name);
-
-        let admin_client: AdminClient<DefaultClientContext> = ClientConfig::new()
-            .set("bootstrap.servers", &self.brokers)
-            .create()
-            .map_err(|e| format!("AdminClient 생성 실패: {:?}", e))?;
+/// 메시지 오프셋 커밋
+fn commit(subscription: &dyn Subscription, message: &BrokerMessage) {
+    if let Err(e) = subscription.commit(message) {
+        error!("{:<12} --> 오프셋 커밋 오류: {:?}", "Consumer", e);
+    }
+}
 
-        let new_topic = NewTopic::new(
-            topic_name,
-            num_partitions,
-            TopicReplication::Fixed(replication_factor),
+/// 이벤트 처리 (실패 시 재시도)
+/// 최종 실패 시 오류 사유와 재시도 횟수 반환
+async fn handle_with_retry<F, Fut>(
+    handler: &F,
+    event: Event,
+    max_retries: u32,
+) -> Result<(), (String, u32)>
+where
+    F: Fn(Event) -> Fut,
+    Fut: std::future::Future<Output = Result<(), Box<dyn std::error::Error>>>,
+{
+    let mut retry_count = 0;
+    loop {
+        let error = match handler(event.clone()).await {
+            Ok(()) => return Ok(()),
+            Err(e) => e.to_string(),
+        };
+        error!(
+            "{:<12} --> 이벤트 처리 오류 (재시도 {}/{}): {}",
+            "Consumer", retry_count, max_retries, error
         );
-
-        match admin_client
-            .create_topics(&[new_topic], &AdminOptions::new())
-            .await
-        {
-            Ok(_) => {
-                info!("{:<12} --> Kafka 토픽 생성 성공: {}", "Manager", topic_name);
-                Ok(())
-            }
-            Err(e) => {
-                error!("{:<12} --> Kafka 토픽 생성 실패: {:?}", "Manager", e);
-                Err(format!("토픽 생성 실패: {:?}", e))
-            }
+        if retry_count >= max_retries {
+            return Err((error, retry_count));
         }
+        retry_count += 1;
+        time::sleep(Duration::from_millis(100 * retry_count as u64)).await;
+    }
+}
+
+/// 데드레터 토픽으로 전송 (성공할 때까지 재시도)
+/// 대기 시간은 100ms부터 두 배씩 늘려 최대 `MAX_DEAD_LETTER_BACKOFF`
+async fn send_dead_letter_with_retry(
+    broker: &dyn MessageBroker,
+    dead_letter_topic: &str,
+    dead_letter: &DeadLetter,
+) {
+    let mut backoff = Duration::from_millis(100);
+    while let Err(e) = send_dead_letter(broker, dead_letter_topic, dead_letter).await {
+        error!(
+            "{:<12} --> 데드레터 전송 오류 ({:?} 후 재시도): {:?}",
+            "Consumer", backoff, e
+        );
+        time::sleep(backoff).await;
+        backoff = (backoff * 2).min(MAX_DEAD_LETTER_BACKOFF);
     }
 }
 
-// endregion: --- Kafka Manager
+/// 데드레터 토픽으로 전송 (오류 사유, 원본 위치, 재시도 횟수는 헤더로 전달)
+async fn send_dead_letter(
+    broker: &dyn MessageBroker,
+    dead_letter_topic: &str,
+    dead_letter: &DeadLetter,
+) -> Result<(), String> {
+    warn!(
+        "{:<12} --> 데드레터 전송: topic={}, partition={}, offset={}, 사유={}",
+        "Consumer", dead_letter.topic, dead_letter.partition, dead_letter.offset, dead_letter.error
+    );
+    let partition = dead_letter.partition.to_string();
+    let offset = dead_letter.offset.to_string();
+    let retry_count = dead_letter.retry_count.to_string();
+    broker
+        .publish(
+            dead_letter_topic,
+            dead_letter.message_key.as_deref().unwrap_or_default(),
+            &dead_letter.payload,
+            &[
+                ("error", dead_letter.error.as_str()),
+                ("original_topic", dead_letter.topic.as_str()),
+                ("original_partition", partition.as_str()),
+                ("original_offset", offset.as_str()),
+                ("retry_count", retry_count.as_str()),
+            ],
+        )
+        .await
+}
+
+// endregion: --- Event Consumption
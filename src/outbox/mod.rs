@@ -0,0 +1,198 @@
+/// 트랜잭셔널 아웃박스 릴레이
+/// 이벤트 저장소는 이벤트와 발행할 메시지를 같은 트랜잭션으로 outbox 테이블에 기록하고,
+/// 릴레이는 발행 대기 중인 메시지를 주기적으로 메시지 브로커(Kafka 등)에 발행한 뒤 발행 완료로 표시한다.
+/// 발행 후 완료 표시 전에 장애가 나면 같은 메시지를 다시 발행하므로 최소 한 번(at-least-once) 전달을 보장한다.
+/// 같은 애그리거트의 메시지는 outbox에 기록된 순서대로 발행한다.
+// region:    --- Imports
+use crate::database::DatabaseManager;
+use crate::message_broker::MessageBroker;
+use chrono::Utc;
+use sqlx::FromRow;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::time::{interval, Duration};
+use tracing::{error, warn};
+
+// endregion: --- Imports
+
+// region:    --- Outbox Relay
+/// 한 번에 발행할 최대 메시지 수
+const BATCH_SIZE: i64 = 100;
+
+/// 재시도 대기 시간 상한 (초)
+const MAX_BACKOFF_SECS: i64 = 60;
+
+/// 선점 유지 시간 (초), 릴레이가 발행 중 중단되면 이 시간 이후 다시 선점
+const CLAIM_LEASE_SECS: i64 = 30;
+
+/// 선점 구간 직렬화를 위한 advisory lock id
+const CLAIM_LOCK_ID: i64 = 0x6f75_7462_6f78; // "outbox"
+
+/// 발행 대기 중인 outbox 메시지
+#[derive(Debug, FromRow)]
+struct OutboxMessage {
+    id: i64,
+    topic: String,
+    message_key: String,
+    payload: serde_json::Value,
+    attempts: i32,
+}
+
+/// 아웃박스 릴레이
+pub struct OutboxRelay {
+    db_manager: Arc<DatabaseManager>,
+    message_broker: Arc<dyn MessageBroker>,
+}
+
+impl OutboxRelay {
+    /// 아웃박스 릴레이 생성
+    pub fn new(db_manager: Arc<DatabaseManager>, message_broker: Arc<dyn MessageBroker>) -> Self {
+        Self {
+            db_manager,
+            message_broker,
+        }
+    }
+
+    /// 아웃박스 릴레이 시작
+    /// 폴링 주기는 `OUTBOX_POLL_INTERVAL_MS` 환경 변수로 지정 (기본 100ms)
+    pub async fn start(&self) {
+        let db_manager = Arc::clone(&self.db_manager);
+        let message_broker = Arc::clone(&self.message_broker);
+        let poll_interval_ms = std::env::var("OUTBOX_POLL_INTERVAL_MS")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .unwrap_or(100);
+        tokio::spawn(async move {
+            let mut interval = interval(Duration::from_millis(poll_interval_ms));
+            loop {
+                interval.tick().await;
+                if let Err(e) = Self::relay_pending(&db_manager, &*message_broker).await {
+                    error!("{:<12} --> outbox 발행 중 오류 발생: {:?}", "Outbox", e);
+                }
+            }
+        });
+    }
+
+    /// 발행 대기 중인 메시지 발행
+    /// 1. 발행할 메시지를 선점하고 트랜잭션 종료 (메시지 브로커 발행 중 행 잠금을 유지하지 않음)
+    /// 2. 선점한 메시지를 순서대로 발행하고 메시지별로 발행 완료 표시
+    /// 3. 발행에 실패하면 지수 백오프로 다음 시도 시각을 미루고, 순서 유지를 위해 같은 애그리거트의 나머지 메시지는 선점 해제
+    async fn relay_pending(
+        db_manager: &DatabaseManager,
+        message_broker: &dyn MessageBroker,
+    ) -> Result<(), String> {
+        let messages = Self::claim_pending(db_manager).await?;
+
+        let mut failed_keys = HashSet::new();
+        for message in messages {
+            if failed_keys.contains(&message.message_key) {
+                Self::unclaim(db_manager, message.id).await?;
+                continue;
+            }
+
+            let result = message_broker
+                .publish(
+                    &message.topic,
+                    &message.message_key,
+                    &message.payload.to_string(),
+                    &[],
+                )
+                .await;
+
+            match result {
+                Ok(()) => {
+                    sqlx::query(
+                        "UPDATE outbox SET sent_at = $1, attempts = attempts + 1, claimed_until = NULL
+                        WHERE id = $2",
+                    )
+                    .bind(Utc::now())
+                    .bind(message.id)
+                    .execute(db_manager.pool())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                }
+                Err(e) => {
+                    let attempts = message.attempts + 1;
+                    let backoff_secs = 2_i64.saturating_pow(attempts as u32).min(MAX_BACKOFF_SECS);
+                    warn!(
+                        "{:<12} --> outbox 발행 실패: id={}, 시도 횟수={}, {}초 후 재시도, {}",
+                        "Outbox", message.id, attempts, backoff_secs, e
+                    );
+                    sqlx::query(
+                        "UPDATE outbox
+                        SET attempts = $1, last_error = $2, next_attempt_at = $3, claimed_until = NULL
+                        WHERE id = $4",
+                    )
+                    .bind(attempts)
+                    .bind(&e)
+                    .bind(Utc::now() + chrono::Duration::seconds(backoff_secs))
+                    .bind(message.id)
+                    .execute(db_manager.pool())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                    failed_keys.insert(message.message_key);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 발행할 메시지 선점
+    /// 애그리거트(메시지 키)별 가장 오래된 발행 대기 메시지가 발행 가능하고 선점되지 않았을 때만 해당 애그리거트의 메시지 선점
+    /// (앞선 메시지가 재시도 대기 중이거나 다른 릴레이가 발행 중이면 뒤의 메시지가 먼저 발행되지 않도록)
+    /// 여러 인스턴스의 선점이 서로 끼어들지 않도록 트랜잭션 단위 advisory lock으로 선점 구간만 직렬화
+    async fn claim_pending(db_manager: &DatabaseManager) -> Result<Vec<OutboxMessage>, String> {
+        let now = Utc::now();
+        let mut tx = db_manager.pool().begin().await.map_err(|e| e.to_string())?;
+
+        sqlx::query("SELECT pg_advisory_xact_lock($1)")
+            .bind(CLAIM_LOCK_ID)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut messages = sqlx::query_as::<_, OutboxMessage>(
+            "UPDATE outbox SET claimed_until = $2
+            WHERE id IN (
+                SELECT o.id
+                FROM outbox o
+                JOIN (
+                    SELECT DISTINCT ON (message_key) message_key, next_attempt_at, claimed_until
+                    FROM outbox
+                    WHERE sent_at IS NULL
+                    ORDER BY message_key, id
+                ) head ON head.message_key = o.message_key
+                WHERE o.sent_at IS NULL
+                AND head.next_attempt_at <= $1
+                AND (head.claimed_until IS NULL OR head.claimed_until <= $1)
+                ORDER BY o.id
+                LIMIT $3
+            )
+            RETURNING id, topic, message_key, payload, attempts",
+        )
+        .bind(now)
+        .bind(now + chrono::Duration::seconds(CLAIM_LEASE_SECS))
+        .bind(BATCH_SIZE)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+
+        messages.sort_by_key(|message| message.id);
+        Ok(messages)
+    }
+
+    /// 메시지 선점 해제 (앞선 메시지가 발행되면 다음 주기에 다시 선점)
+    async fn unclaim(db_manager: &DatabaseManager, id: i64) -> Result<(), String> {
+        sqlx::query("UPDATE outbox SET claimed_until = NULL WHERE id = $1")
+            .bind(id)
+            .execute(db_manager.pool())
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+// endregion: --- Outbox Relay
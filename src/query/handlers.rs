@@ -2,6 +2,7 @@
 use super::queries;
 use crate::bidding::model::{Bid, Item};
 use crate::database::DatabaseManager;
+use crate::event_store::{DeadLetterRecord, Event};
 use sqlx::Error as SqlxError;
 use sqlx::Row;
 use tracing::info;
@@ -66,6 +67,29 @@ pub async fn get_bid_history(
         .await
 }
 
+/// 입찰 금액 비공개 여부 조회 (비공개 입찰 경매는 종료 전까지 입찰 금액 비공개, 상품이 없으면 공개)
+pub async fn hides_bid_amounts(
+    db_manager: &DatabaseManager,
+    item_id: i64,
+) -> Result<bool, SqlxError> {
+    info!(
+        "{:<12} --> 입찰 금액 비공개 여부 조회 id: {}",
+        "Query", item_id
+    );
+    db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                let result = sqlx::query(queries::HIDES_BID_AMOUNTS)
+                    .bind(item_id)
+                    .fetch_optional(&mut **tx)
+                    .await?;
+
+                Ok(result.is_some_and(|row| row.get("hides_bid_amounts")))
+            })
+        })
+        .await
+}
+
 /// 모든 상품 조회
 pub async fn get_all_items(db_manager: &DatabaseManager) -> Result<Vec<Item>, sqlx::Error> {
     info!("{:<12} --> 모든 상품 조회", "Query");
@@ -113,24 +137,22 @@ pub async fn get_item_bids(
         .await
 }
 
-/// 상품 즉시 구매 가격 조회
-pub async fn get_item_buy_now_price(
+/// 이벤트 도입 전 입찰 조회
+pub async fn get_legacy_bids(
     db_manager: &DatabaseManager,
     item_id: i64,
-) -> Result<i64, SqlxError> {
+) -> Result<Vec<Bid>, SqlxError> {
     info!(
-        "{:<12} --> 상품 즉시 구매 가격 조회 id: {}",
+        "{:<12} --> 이벤트 도입 전 입찰 조회 id: {}",
         "Query", item_id
     );
     db_manager
         .transaction(|tx| {
             Box::pin(async move {
-                let result = sqlx::query(queries::GET_ITEM_BUY_NOW_PRICE)
+                sqlx::query_as::<_, Bid>(queries::GET_LEGACY_BIDS)
                     .bind(item_id)
-                    .fetch_one(&mut **tx)
-                    .await?;
-
-                Ok(result.get("buy_now_price"))
+                    .fetch_all(&mut **tx)
+                    .await
             })
         })
         .await
@@ -156,21 +178,99 @@ pub async fn get_item_version(
         .await
 }
 
-/// 상품 현재 가격 조회
-pub async fn get_item_current_price(
+/// 조회 모델에 반영된 상품 이벤트 버전 조회
+pub async fn get_projection_version(
     db_manager: &DatabaseManager,
     item_id: i64,
 ) -> Result<i64, SqlxError> {
-    info!("{:<12} --> 상품 현재 가격 조회 id: {}", "Query", item_id);
+    info!(
+        "{:<12} --> 조회 모델 반영 버전 조회 id: {}",
+        "Query", item_id
+    );
     db_manager
         .transaction(|tx| {
             Box::pin(async move {
-                let result = sqlx::query(queries::GET_ITEM_CURRENT_PRICE)
+                let result = sqlx::query(queries::GET_PROJECTION_VERSION)
                     .bind(item_id)
                     .fetch_one(&mut **tx)
                     .await?;
 
-                Ok(result.get("current_price"))
+                Ok(result.get("version"))
+            })
+        })
+        .await
+}
+
+/// 마지막 이벤트 위치 조회 (이벤트가 없으면 0)
+pub async fn get_last_event_position(db_manager: &DatabaseManager) -> Result<i64, SqlxError> {
+    info!("{:<12} --> 마지막 이벤트 위치 조회", "Query");
+    db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                let result = sqlx::query(queries::GET_LAST_EVENT_POSITION)
+                    .fetch_one(&mut **tx)
+                    .await?;
+
+                Ok(result.get("position"))
+            })
+        })
+        .await
+}
+
+/// 위치 이후의 커밋된 이벤트 조회 (트랜잭션 id, 위치 순, 최대 `limit`건)
+pub async fn get_events_after_position(
+    db_manager: &DatabaseManager,
+    after_position: i64,
+    item_id: Option<i64>,
+    limit: i64,
+) -> Result<Vec<Event>, SqlxError> {
+    info!(
+        "{:<12} --> 이벤트 조회 after_position: {}, item_id: {:?}",
+        "Query", after_position, item_id
+    );
+    db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query_as::<_, Event>(queries::GET_EVENTS_AFTER_POSITION)
+                    .bind(after_position)
+                    .bind(item_id)
+                    .bind(limit)
+                    .fetch_all(&mut **tx)
+                    .await
+            })
+        })
+        .await
+}
+
+/// 재처리 대기 중인 데드레터 조회
+pub async fn get_dead_letters(
+    db_manager: &DatabaseManager,
+) -> Result<Vec<DeadLetterRecord>, SqlxError> {
+    info!("{:<12} --> 데드레터 조회", "Query");
+    db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query_as::<_, DeadLetterRecord>(queries::GET_DEAD_LETTERS)
+                    .fetch_all(&mut **tx)
+                    .await
+            })
+        })
+        .await
+}
+
+/// 데드레터 조회
+pub async fn get_dead_letter(
+    db_manager: &DatabaseManager,
+    dead_letter_id: i64,
+) -> Result<DeadLetterRecord, SqlxError> {
+    info!("{:<12} --> 데드레터 조회 id: {}", "Query", dead_letter_id);
+    db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query_as::<_, DeadLetterRecord>(queries::GET_DEAD_LETTER)
+                    .bind(dead_letter_id)
+                    .fetch_one(&mut **tx)
+                    .await
             })
         })
         .await
@@ -0,0 +1,103 @@
+/// 인메모리 조회 모델 저장소
+/// 데이터베이스 없이 명령 처리 로직을 테스트할 때 사용하며, 상품과 입찰은 테스트에서 직접 등록
+/// 직접 등록한 입찰은 이벤트 도입 전 입찰로 취급
+// region:    --- Imports
+use super::repository::{BidRepository, ItemRepository};
+use crate::bidding::model::{Bid, Item};
+use crate::error::AuctionError;
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+// endregion: --- Imports
+
+// region:    --- In-Memory Repository
+/// 인메모리 조회 모델 저장소
+#[derive(Default)]
+pub struct InMemoryRepository {
+    items: Mutex<BTreeMap<i64, Item>>,
+    bids: Mutex<Vec<Bid>>,
+}
+
+impl InMemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 상품 등록 (같은 id의 상품이 있으면 교체)
+    pub fn insert_item(&self, item: Item) {
+        self.items
+            .lock()
+            .expect("상품 저장소 잠금 실패")
+            .insert(item.id, item);
+    }
+
+    /// 입찰 등록
+    pub fn insert_bid(&self, bid: Bid) {
+        self.bids.lock().expect("입찰 저장소 잠금 실패").push(bid);
+    }
+}
+
+#[async_trait]
+impl ItemRepository for InMemoryRepository {
+    async fn get_item(&self, item_id: i64) -> Result<Item, AuctionError> {
+        self.items
+            .lock()
+            .map_err(|e| AuctionError::Internal(e.to_string()))?
+            .get(&item_id)
+            .cloned()
+            .ok_or_else(|| AuctionError::not_found("resource.item", item_id))
+    }
+
+    async fn get_all_items(&self) -> Result<Vec<Item>, AuctionError> {
+        Ok(self
+            .items
+            .lock()
+            .map_err(|e| AuctionError::Internal(e.to_string()))?
+            .values()
+            .cloned()
+            .collect())
+    }
+
+    async fn get_legacy_bids(&self, item_id: i64) -> Result<Vec<Bid>, AuctionError> {
+        let mut bids: Vec<Bid> = self
+            .bids
+            .lock()
+            .map_err(|e| AuctionError::Internal(e.to_string()))?
+            .iter()
+            .filter(|bid| bid.item_id == item_id)
+            .cloned()
+            .collect();
+        bids.sort_by_key(|bid| (bid.bid_time, bid.id));
+        Ok(bids)
+    }
+}
+
+#[async_trait]
+impl BidRepository for InMemoryRepository {
+    async fn get_bid_history(&self, item_id: i64) -> Result<Vec<Bid>, AuctionError> {
+        let mut bids: Vec<Bid> = self
+            .bids
+            .lock()
+            .map_err(|e| AuctionError::Internal(e.to_string()))?
+            .iter()
+            .filter(|bid| bid.item_id == item_id)
+            .cloned()
+            .collect();
+        bids.sort_by_key(|bid| std::cmp::Reverse((bid.bid_time, bid.id)));
+        Ok(bids)
+    }
+
+    async fn get_highest_bid(&self, item_id: i64) -> Result<Option<i64>, AuctionError> {
+        Ok(self
+            .bids
+            .lock()
+            .map_err(|e| AuctionError::Internal(e.to_string()))?
+            .iter()
+            .filter(|bid| bid.item_id == item_id && bid.retracted_at.is_none())
+            .map(|bid| bid.bid_amount)
+            .max())
+    }
+}
+
+// endregion: --- In-Memory Repository
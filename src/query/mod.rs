@@ -1,2 +1,4 @@
 pub mod handlers;
+pub mod memory;
 pub mod queries;
+pub mod repository;
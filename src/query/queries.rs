@@ -1,39 +1,141 @@
 /// 경매 상태 조회
-pub const GET_AUCTION_STATE: &str = "SELECT id, title, description, starting_price, current_price, start_time, end_time, seller, status, created_at FROM items WHERE id = $1";
+/// 최저 낙찰가 충족 여부는 애그리거트와 같이 유효한 입찰 기준 (비공개 입찰 경매는 종료 전까지 미충족)
+pub const GET_AUCTION_STATE: &str = r#"
+    SELECT id, title, description, starting_price, current_price, buy_now_price, reserve_price,
+           (reserve_price IS NULL OR (
+               NOT (auction_type IN ('SEALED_FIRST_PRICE', 'SEALED_SECOND_PRICE') AND status IN ('SCHEDULED', 'ACTIVE'))
+               AND EXISTS (
+                   SELECT 1 FROM bids
+                   WHERE bids.item_id = items.id AND bids.retracted_at IS NULL AND bids.bid_amount >= items.reserve_price
+               )
+           )) AS reserve_met, bid_increments,
+           start_time, end_time, soft_close_window_secs, soft_close_extension_secs, auction_type, price_drop_amount,
+           price_drop_interval_secs, seller, status, winner_id, created_at
+    FROM items
+    WHERE id = $1
+"#;
 
 /// 최고 입찰 조회
 pub const GET_HIGHEST_BID: &str =
-    "SELECT MAX(bid_amount) as highest_bid FROM bids WHERE item_id = $1";
+    "SELECT MAX(bid_amount) as highest_bid FROM bids WHERE item_id = $1 AND retracted_at IS NULL";
 
-/// 입찰 이력 조회
+/// 입찰 이력 조회 (철회된 입찰 포함, `retracted_at`으로 구분)
 pub const GET_BID_HISTORY: &str = r#"
-    SELECT id, item_id, bidder_id, bid_amount, bid_time
+    SELECT id, item_id, bidder_id, bid_amount, bid_time, is_auto, retracted_at
     FROM bids
     WHERE item_id = $1
-    ORDER BY bid_time DESC
+    ORDER BY bid_time DESC, id DESC
+"#;
+
+/// 입찰 금액 비공개 여부 조회 (종료되지 않은 비공개 입찰 경매)
+pub const HIDES_BID_AMOUNTS: &str = r#"
+    SELECT auction_type IN ('SEALED_FIRST_PRICE', 'SEALED_SECOND_PRICE')
+           AND status IN ('SCHEDULED', 'ACTIVE') AS hides_bid_amounts
+    FROM items
+    WHERE id = $1
+"#;
+
+/// 재처리 대기 중인 데드레터 조회
+pub const GET_DEAD_LETTERS: &str = r#"
+    SELECT id, topic, partition_id, message_offset, message_key, payload, error, retry_count, created_at, replayed_at
+    FROM dead_letters
+    WHERE replayed_at IS NULL
+    ORDER BY id
+"#;
+
+/// 데드레터 조회
+pub const GET_DEAD_LETTER: &str = r#"
+    SELECT id, topic, partition_id, message_offset, message_key, payload, error, retry_count, created_at, replayed_at
+    FROM dead_letters
+    WHERE id = $1
 "#;
 
 /// 모든 상품 조회
-pub const GET_ALL_ITEMS: &str = 
-    "SELECT id, title, description, starting_price, current_price, buy_now_price, start_time, end_time, seller, status, created_at FROM items ORDER BY created_at DESC";
+pub const GET_ALL_ITEMS: &str = r#"
+    SELECT id, title, description, starting_price, current_price, buy_now_price, reserve_price,
+           (reserve_price IS NULL OR (
+               NOT (auction_type IN ('SEALED_FIRST_PRICE', 'SEALED_SECOND_PRICE') AND status IN ('SCHEDULED', 'ACTIVE'))
+               AND EXISTS (
+                   SELECT 1 FROM bids
+                   WHERE bids.item_id = items.id AND bids.retracted_at IS NULL AND bids.bid_amount >= items.reserve_price
+               )
+           )) AS reserve_met, bid_increments,
+           start_time, end_time, soft_close_window_secs, soft_close_extension_secs, auction_type, price_drop_amount,
+           price_drop_interval_secs, seller, status, winner_id, created_at
+    FROM items
+    ORDER BY created_at DESC
+"#;
 
 /// 상품 조회
-pub const GET_ITEM: &str = 
-    "SELECT id, title, description, starting_price, current_price, buy_now_price, start_time, end_time, seller, status, created_at FROM items WHERE id = $1";
+pub const GET_ITEM: &str = r#"
+    SELECT id, title, description, starting_price, current_price, buy_now_price, reserve_price,
+           (reserve_price IS NULL OR (
+               NOT (auction_type IN ('SEALED_FIRST_PRICE', 'SEALED_SECOND_PRICE') AND status IN ('SCHEDULED', 'ACTIVE'))
+               AND EXISTS (
+                   SELECT 1 FROM bids
+                   WHERE bids.item_id = items.id AND bids.retracted_at IS NULL AND bids.bid_amount >= items.reserve_price
+               )
+           )) AS reserve_met, bid_increments,
+           start_time, end_time, soft_close_window_secs, soft_close_extension_secs, auction_type, price_drop_amount,
+           price_drop_interval_secs, seller, status, winner_id, created_at
+    FROM items
+    WHERE id = $1
+"#;
 
 /// 상품 입찰 조회
 pub const GET_ITEM_BIDS: &str = r#"
-    SELECT id, item_id, bidder_id, bid_amount, bid_time
+    SELECT id, item_id, bidder_id, bid_amount, bid_time, is_auto, retracted_at
     FROM bids
     WHERE item_id = $1
-    ORDER BY bid_time DESC
+    ORDER BY bid_time DESC, id DESC
 "#;
 
-/// 상품 현재 가격 조회
-pub const GET_ITEM_CURRENT_PRICE: &str = "SELECT current_price FROM items WHERE id = $1";
-
-/// 상품 즉시 구매 가격 조회
-pub const GET_ITEM_BUY_NOW_PRICE: &str = "SELECT buy_now_price FROM items WHERE id = $1";
+/// 이벤트 도입 전 입찰 조회 (이벤트 버전이 없는 입찰, 철회된 입찰 포함, 입찰 순)
+pub const GET_LEGACY_BIDS: &str = r#"
+    SELECT id, item_id, bidder_id, bid_amount, bid_time, is_auto, retracted_at
+    FROM bids
+    WHERE item_id = $1 AND event_version IS NULL
+    ORDER BY bid_time, id
+"#;
 
 /// 상품 버전 조회
-pub const GET_ITEM_VERSION: &str = "SELECT COALESCE(MAX(version), 0) as version FROM events WHERE aggregate_id = $1";
\ No newline at end of file
+pub const GET_ITEM_VERSION: &str =
+    "SELECT COALESCE(MAX(version), 0) as version FROM events WHERE aggregate_id = $1";
+
+/// 조회 모델에 반영된 상품 이벤트 버전 조회
+pub const GET_PROJECTION_VERSION: &str = r#"
+    SELECT COALESCE(
+        (SELECT last_version FROM projection_offsets WHERE aggregate_id = $1), 0
+    ) AS version
+"#;
+
+/// 마지막 이벤트 위치 조회
+/// 진행 중인 트랜잭션보다 먼저 시작한 트랜잭션의 이벤트 중 (트랜잭션 id, 위치) 순으로 마지막 이벤트 (없으면 0)
+pub const GET_LAST_EVENT_POSITION: &str = r#"
+    SELECT COALESCE((
+        SELECT position FROM events
+        WHERE transaction_id < pg_snapshot_xmin(pg_current_snapshot())
+        ORDER BY transaction_id DESC, position DESC
+        LIMIT 1
+    ), 0) AS position
+"#;
+
+/// 위치 이후의 이벤트 조회 (상품 id가 NULL이면 전체 상품)
+/// 진행 중인 트랜잭션보다 먼저 시작한 트랜잭션의 이벤트만 (트랜잭션 id, 위치) 순으로 조회하므로, 이후 커밋되는 이벤트가 조회한 이벤트 앞에 끼어들지 않음
+/// 위치의 이벤트가 없으면 위치 순으로 이후 이벤트 조회
+/// 데이터베이스(클러스터 전체)에 더 먼저 시작한 쓰기 트랜잭션이 열려 있는 동안에는 이후 트랜잭션의 이벤트를 반환하지 않으므로, 오래 실행되는 트랜잭션이 끝날 때까지 전달이 지연됨
+pub const GET_EVENTS_AFTER_POSITION: &str = r#"
+    WITH after AS (
+        SELECT transaction_id FROM events WHERE position = $1
+    )
+    SELECT id, aggregate_id, event_type, data, timestamp, version, idempotency_key, position
+    FROM events
+    WHERE transaction_id < pg_snapshot_xmin(pg_current_snapshot())
+    AND ($2::BIGINT IS NULL OR aggregate_id = $2)
+    AND CASE WHEN EXISTS (SELECT 1 FROM after)
+        THEN (transaction_id, position) > ((SELECT transaction_id FROM after), $1)
+        ELSE position > $1
+    END
+    ORDER BY transaction_id, position
+    LIMIT $3
+"#;
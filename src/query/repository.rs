@@ -0,0 +1,90 @@
+/// 조회 모델 저장소
+/// 명령 처리와 애그리거트 재구성에서 조회 모델(상품, 입찰)에 접근할 때 사용하는 트레이트
+/// 1. PostgreSQL: 운영 환경 (`query::handlers` 쿼리 함수 사용)
+/// 2. 인메모리: 데이터베이스 없이 명령 처리 로직을 테스트할 때 사용 (`query::memory`)
+// region:    --- Imports
+use super::handlers;
+use crate::bidding::model::{Bid, Item};
+use crate::database::DatabaseManager;
+use crate::error::AuctionError;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// endregion: --- Imports
+
+// region:    --- Repository Traits
+/// 상품 조회 모델 저장소 트레이트
+#[async_trait]
+pub trait ItemRepository: Send + Sync {
+    /// 상품 조회
+    async fn get_item(&self, item_id: i64) -> Result<Item, AuctionError>;
+
+    /// 모든 상품 조회
+    async fn get_all_items(&self) -> Result<Vec<Item>, AuctionError>;
+
+    /// 이벤트 도입 전 입찰 조회 (이벤트 버전 없이 조회 모델에만 기록된 입찰, 입찰 순)
+    async fn get_legacy_bids(&self, item_id: i64) -> Result<Vec<Bid>, AuctionError>;
+}
+
+/// 입찰 조회 모델 저장소 트레이트
+#[async_trait]
+pub trait BidRepository: Send + Sync {
+    /// 입찰 이력 조회 (최신 입찰 순)
+    async fn get_bid_history(&self, item_id: i64) -> Result<Vec<Bid>, AuctionError>;
+
+    /// 최고 입찰가 조회
+    async fn get_highest_bid(&self, item_id: i64) -> Result<Option<i64>, AuctionError>;
+}
+
+// endregion: --- Repository Traits
+
+// region:    --- Postgres Repository
+/// PostgreSQL 조회 모델 저장소
+pub struct PostgresRepository {
+    db_manager: Arc<DatabaseManager>,
+}
+
+impl PostgresRepository {
+    /// PostgreSQL 조회 모델 저장소 생성
+    pub fn new(db_manager: Arc<DatabaseManager>) -> Self {
+        Self { db_manager }
+    }
+}
+
+#[async_trait]
+impl ItemRepository for PostgresRepository {
+    async fn get_item(&self, item_id: i64) -> Result<Item, AuctionError> {
+        handlers::get_item(&self.db_manager, item_id)
+            .await
+            .map_err(|e| AuctionError::from_query(e, "resource.item", item_id))
+    }
+
+    async fn get_all_items(&self) -> Result<Vec<Item>, AuctionError> {
+        handlers::get_all_items(&self.db_manager)
+            .await
+            .map_err(AuctionError::from)
+    }
+
+    async fn get_legacy_bids(&self, item_id: i64) -> Result<Vec<Bid>, AuctionError> {
+        handlers::get_legacy_bids(&self.db_manager, item_id)
+            .await
+            .map_err(AuctionError::from)
+    }
+}
+
+#[async_trait]
+impl BidRepository for PostgresRepository {
+    async fn get_bid_history(&self, item_id: i64) -> Result<Vec<Bid>, AuctionError> {
+        handlers::get_bid_history(&self.db_manager, item_id)
+            .await
+            .map_err(AuctionError::from)
+    }
+
+    async fn get_highest_bid(&self, item_id: i64) -> Result<Option<i64>, AuctionError> {
+        handlers::get_highest_bid(&self.db_manager, item_id)
+            .await
+            .map_err(AuctionError::from)
+    }
+}
+
+// endregion: --- Postgres Repository
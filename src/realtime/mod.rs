@@ -0,0 +1,270 @@
+/// 실시간 경매 알림
+/// `EventConsumer`가 조회 모델에 반영한 이벤트를 WebSocket(`/ws/auction/:id`)으로 구독자에게 전달
+/// WebSocket을 사용할 수 없는 클라이언트는 SSE(`sse`) 사용
+/// 클라이언트는 연결 URL의 `last_version` 쿼리나 `{"last_version": N}` 메시지로 이어받을 버전을 지정
+/// 1. 연결 시 현재 상품 상태(snapshot)와 반영 버전 전송 (`last_version`을 지정하면 생략)
+/// 2. 마지막으로 받은 버전 이후의 이벤트를 이벤트 저장소에서 조회하여 전송 (재연결 시 누락 방지)
+/// 3. 이후 반영되는 이벤트를 실시간으로 전송
+// region:    --- Imports
+use crate::bidding::model::Item;
+use crate::database::DatabaseManager;
+use crate::error::AuctionError;
+use crate::event_store::{Event, EventStore, PostgresEventStore};
+use crate::query;
+use axum::extract::ws::{Message, WebSocket};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{info, warn};
+
+pub mod sse;
+
+// endregion: --- Imports
+
+// region:    --- Auction Updates
+/// 알림 채널 용량 (구독자가 처리하지 못한 이벤트가 넘치면 이벤트 저장소에서 다시 조회)
+const UPDATE_CHANNEL_CAPACITY: usize = 1024;
+
+/// 구독자에게 전달하지 않는 이벤트 타입 (입찰자의 최대 입찰가, 비공개 입찰 금액은 비공개)
+const PRIVATE_EVENT_TYPES: &[&str] = &["ProxyBidRegistered", "SealedBidSubmitted"];
+
+/// 구독자에게 전달하지 않는 이벤트 데이터 항목 (상품 등록/수정 이벤트의 최저 낙찰가는 비공개)
+const PRIVATE_FIELDS: &[&str] = &["reserve_price"];
+
+/// 구독자에게 전달하는 이벤트인지 확인
+fn is_public(event: &Event) -> bool {
+    !PRIVATE_EVENT_TYPES.contains(&event.event_type.as_str())
+}
+
+/// 조회 모델에 반영된 이벤트 알림
+pub struct AuctionUpdates {
+    sender: broadcast::Sender<Arc<Event>>,
+}
+
+impl Default for AuctionUpdates {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(UPDATE_CHANNEL_CAPACITY);
+        Self { sender }
+    }
+}
+
+impl AuctionUpdates {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 반영된 이벤트 알림 (구독자가 없으면 무시)
+    pub fn publish(&self, events: Vec<Event>) {
+        for event in events {
+            let _ = self.sender.send(Arc::new(event));
+        }
+    }
+
+    /// 알림 구독
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<Event>> {
+        self.sender.subscribe()
+    }
+}
+
+// endregion: --- Auction Updates
+
+// region:    --- Messages
+/// 서버 -> 클라이언트 메시지
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// 현재 상품 상태 (`version`까지 반영된 상태)
+    Snapshot { version: i64, item: Box<Item> },
+    /// 경매 이벤트 (상품 등록/수정/철회, 입찰, 즉시 구매, 가격 하락, 경매 연장, 시작, 종료)
+    Event {
+        item_id: i64,
+        version: i64,
+        event_type: String,
+        data: serde_json::Value,
+        timestamp: DateTime<Utc>,
+    },
+}
+
+impl ServerMessage {
+    pub(crate) fn event(event: &Event) -> Self {
+        // 이벤트 데이터는 이벤트 타입으로 감싸져 있으므로 내부 값만 전달
+        let mut data = event
+            .data
+            .get(&event.event_type)
+            .cloned()
+            .unwrap_or_else(|| event.data.clone());
+        if let Some(fields) = data.as_object_mut() {
+            for field in PRIVATE_FIELDS {
+                fields.remove(*field);
+            }
+        }
+        Self::Event {
+            item_id: event.aggregate_id,
+            version: event.version,
+            event_type: event.event_type.clone(),
+            data,
+            timestamp: event.timestamp,
+        }
+    }
+}
+
+/// 클라이언트 -> 서버 메시지
+#[derive(Debug, Deserialize)]
+pub struct ResumeRequest {
+    /// 마지막으로 받은 이벤트 버전
+    pub last_version: i64,
+}
+
+// endregion: --- Messages
+
+// region:    --- Subscription
+/// 상품 알림 구독 연결 처리
+pub async fn serve(
+    mut socket: WebSocket,
+    db_manager: Arc<DatabaseManager>,
+    updates: Arc<AuctionUpdates>,
+    item_id: i64,
+    last_version: Option<i64>,
+) {
+    info!(
+        "{:<12} --> 구독 시작: item_id={}, last_version={:?}",
+        "Realtime", item_id, last_version
+    );
+    // 누락을 막기 위해 초기 전송 전에 먼저 구독
+    let mut receiver = updates.subscribe();
+    let event_store = PostgresEventStore::new(Arc::clone(&db_manager));
+
+    let mut subscription = Subscription {
+        socket: &mut socket,
+        event_store: &event_store,
+        item_id,
+        last_sent: 0,
+    };
+    let started = match last_version {
+        Some(last_version) => subscription.resume(last_version).await,
+        None => match subscription.send_snapshot(&db_manager).await {
+            Ok(()) => subscription.catch_up().await,
+            Err(e) => Err(e),
+        },
+    };
+    if let Err(e) = started {
+        warn!("{:<12} --> 초기 전송 실패: {}", "Realtime", e);
+        return;
+    }
+
+    loop {
+        let incoming = tokio::select! {
+            message = subscription.socket.recv() => match message {
+                Some(Ok(Message::Text(text))) => Incoming::Client(text),
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue,
+            },
+            update = receiver.recv() => match update {
+                Ok(event) => Incoming::Update(event),
+                Err(RecvError::Lagged(skipped)) => Incoming::Lagged(skipped),
+                Err(RecvError::Closed) => break,
+            },
+        };
+        let result = match incoming {
+            Incoming::Client(text) => match serde_json::from_str::<ResumeRequest>(&text) {
+                Ok(request) => subscription.resume(request.last_version).await,
+                Err(e) => {
+                    warn!("{:<12} --> 알 수 없는 메시지: {}", "Realtime", e);
+                    Ok(())
+                }
+            },
+            Incoming::Update(event) => subscription.send_event(&event).await,
+            // 채널이 넘쳐 놓친 이벤트는 이벤트 저장소에서 다시 조회
+            Incoming::Lagged(skipped) => {
+                warn!(
+                    "{:<12} --> 알림 {}건 누락: 이벤트 저장소에서 재조회",
+                    "Realtime", skipped
+                );
+                subscription.catch_up().await
+            }
+        };
+        if let Err(e) = result {
+            warn!("{:<12} --> 전송 실패: {}", "Realtime", e);
+            break;
+        }
+    }
+    info!("{:<12} --> 구독 종료: item_id={}", "Realtime", item_id);
+}
+
+/// 구독 연결에서 받은 입력
+enum Incoming {
+    /// 클라이언트 메시지
+    Client(String),
+    /// 반영된 이벤트 알림
+    Update(Arc<Event>),
+    /// 놓친 알림 수
+    Lagged(u64),
+}
+
+/// 구독 연결 상태
+struct Subscription<'a> {
+    socket: &'a mut WebSocket,
+    event_store: &'a PostgresEventStore,
+    item_id: i64,
+    /// 마지막으로 전송한 이벤트 버전
+    last_sent: i64,
+}
+
+impl Subscription<'_> {
+    /// 현재 상품 상태 전송
+    /// 반영 버전을 먼저 조회하므로, 상품 상태가 더 최신이면 일부 이벤트를 다시 받을 수 있음 (누락은 없음)
+    async fn send_snapshot(&mut self, db_manager: &DatabaseManager) -> Result<(), AuctionError> {
+        let version = query::handlers::get_projection_version(db_manager, self.item_id).await?;
+        let item = query::handlers::get_item(db_manager, self.item_id)
+            .await
+            .map_err(|e| AuctionError::from_query(e, "resource.item", self.item_id))?;
+        self.send(&ServerMessage::Snapshot {
+            version,
+            item: Box::new(item),
+        })
+        .await?;
+        self.last_sent = version;
+        Ok(())
+    }
+
+    /// 지정한 버전 이후부터 이어받기
+    async fn resume(&mut self, last_version: i64) -> Result<(), AuctionError> {
+        self.last_sent = last_version;
+        self.catch_up().await
+    }
+
+    /// 마지막으로 전송한 버전 이후의 이벤트를 이벤트 저장소에서 조회하여 전송
+    async fn catch_up(&mut self) -> Result<(), AuctionError> {
+        let events = self
+            .event_store
+            .load_events(self.item_id, self.last_sent)
+            .await?;
+        for event in events {
+            self.send_event(&event).await?;
+        }
+        Ok(())
+    }
+
+    /// 구독 상품의 새 이벤트 전송 (이미 전송한 버전은 건너뜀)
+    async fn send_event(&mut self, event: &Event) -> Result<(), AuctionError> {
+        if event.aggregate_id != self.item_id || event.version <= self.last_sent {
+            return Ok(());
+        }
+        if is_public(event) {
+            self.send(&ServerMessage::event(event)).await?;
+        }
+        self.last_sent = event.version;
+        Ok(())
+    }
+
+    async fn send(&mut self, message: &ServerMessage) -> Result<(), AuctionError> {
+        let text = serde_json::to_string(message)?;
+        self.socket
+            .send(Message::Text(text))
+            .await
+            .map_err(|e| AuctionError::Internal(e.to_string()))
+    }
+}
+
+// endregion: --- Subscription
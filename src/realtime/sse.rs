@@ -0,0 +1,155 @@
+/// 실시간 경매 알림 (Server-Sent Events)
+/// WebSocket을 사용할 수 없는 프록시 환경을 위해 같은 이벤트를 SSE로 전달
+/// 1. `/items/stream`: 전체 상품, `/auction/:id/stream`: 지정한 상품
+/// 2. SSE 이벤트 id는 이벤트 저장소의 이벤트 위치(`events.position`), 이벤트 이름은 이벤트 타입
+/// 3. 재연결 시 `Last-Event-ID` 헤더를 보내면 해당 이벤트 이후의 이벤트를 이벤트 저장소에서 조회하여 먼저 전송
+/// 4. 반영 알림은 조회 시점으로만 사용하고 이벤트는 항상 이벤트 저장소에서 커밋이 끝난 이벤트만 (트랜잭션 id, 위치) 순으로 조회
+///    (상품별로 반영 순서가 달라도, 먼저 위치를 발급받은 이벤트가 나중에 커밋되어도 건너뛰지 않음)
+/// 5. 상품 구독은 진행 중인 다른 상품의 트랜잭션 때문에 이벤트를 조회하지 못하면, 다음 알림은 상품과 관계없이 다시 조회
+/// 6. 클러스터의 어떤 데이터베이스에서든 더 먼저 시작한 쓰기 트랜잭션이 열려 있으면 그 트랜잭션이 끝날 때까지 이후 이벤트 전달이 멈춤
+///    (오래 실행되는 트랜잭션이나 유휴 상태로 열린 트랜잭션은 SSE 전달 지연의 원인)
+// region:    --- Imports
+use super::{is_public, AuctionUpdates, ServerMessage};
+use crate::database::DatabaseManager;
+use crate::event_store::Event;
+use crate::query;
+use axum::http::HeaderMap;
+use axum::response::sse::{Event as SseEvent, KeepAlive, Sse};
+use futures_util::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{info, warn};
+
+// endregion: --- Imports
+
+// region:    --- Event Stream
+/// 재연결 헤더 이름
+pub const LAST_EVENT_ID_HEADER: &str = "Last-Event-ID";
+
+/// 이벤트 저장소에서 한 번에 조회하는 이벤트 수
+const CATCH_UP_BATCH_SIZE: i64 = 500;
+
+/// 요청 헤더에서 마지막으로 받은 이벤트 위치 추출
+pub fn last_event_id(headers: &HeaderMap) -> Option<i64> {
+    headers
+        .get(LAST_EVENT_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<i64>().ok())
+}
+
+/// SSE 응답 생성
+/// `item_id`가 없으면 전체 상품의 이벤트 전달
+pub fn stream(
+    db_manager: Arc<DatabaseManager>,
+    updates: &AuctionUpdates,
+    item_id: Option<i64>,
+    last_event_id: Option<i64>,
+) -> Sse<impl Stream<Item = Result<SseEvent, Infallible>>> {
+    info!(
+        "{:<12} --> SSE 구독 시작: item_id={:?}, last_event_id={:?}",
+        "Realtime", item_id, last_event_id
+    );
+    // 누락을 막기 위해 이전 이벤트 조회 전에 먼저 구독
+    let state = EventStream {
+        db_manager,
+        receiver: updates.subscribe(),
+        item_id,
+        pending: VecDeque::new(),
+        last_position: last_event_id,
+        catching_up: true,
+        stalled: false,
+    };
+    let events = stream::unfold(state, |mut state| async move {
+        let event = state.next().await?;
+        Some((Ok(to_sse_event(&event)), state))
+    });
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
+
+/// SSE 이벤트 변환
+fn to_sse_event(event: &Event) -> SseEvent {
+    let message = ServerMessage::event(event);
+    SseEvent::default()
+        .id(event.position.to_string())
+        .event(event.event_type.as_str())
+        .json_data(&message)
+        .unwrap_or_else(|_| SseEvent::default().comment("serialize error"))
+}
+
+/// SSE 구독 상태
+struct EventStream {
+    db_manager: Arc<DatabaseManager>,
+    receiver: broadcast::Receiver<Arc<Event>>,
+    item_id: Option<i64>,
+    /// 전송 대기 이벤트
+    pending: VecDeque<Event>,
+    /// 마지막으로 조회한 이벤트 위치 (None이면 구독 시점의 마지막 위치부터)
+    last_position: Option<i64>,
+    /// 이벤트 저장소에서 이벤트를 조회해야 하는지 여부
+    catching_up: bool,
+    /// 마지막 조회에서 이벤트를 받지 못했는지 여부 (다른 상품의 알림에도 다시 조회)
+    stalled: bool,
+}
+
+impl EventStream {
+    /// 다음 전송할 이벤트 (연결을 종료해야 하면 None)
+    async fn next(&mut self) -> Option<Event> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                if is_public(&event) {
+                    return Some(event);
+                }
+                continue;
+            }
+
+            if self.catching_up {
+                if let Err(e) = self.catch_up().await {
+                    warn!("{:<12} --> SSE 이벤트 조회 실패: {}", "Realtime", e);
+                    return None;
+                }
+                continue;
+            }
+
+            // 구독 상품의 이벤트가 반영되었거나 알림을 놓쳤으면 이벤트 저장소에서 조회
+            match self.receiver.recv().await {
+                Ok(event) => {
+                    self.catching_up = self.stalled
+                        || self
+                            .item_id
+                            .is_none_or(|item_id| item_id == event.aggregate_id)
+                }
+                Err(RecvError::Lagged(_)) => self.catching_up = true,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// 마지막으로 조회한 위치 이후의 이벤트 조회
+    async fn catch_up(&mut self) -> Result<(), sqlx::Error> {
+        let Some(last_position) = self.last_position else {
+            self.last_position =
+                Some(query::handlers::get_last_event_position(&self.db_manager).await?);
+            self.catching_up = false;
+            return Ok(());
+        };
+        let events = query::handlers::get_events_after_position(
+            &self.db_manager,
+            last_position,
+            self.item_id,
+            CATCH_UP_BATCH_SIZE,
+        )
+        .await?;
+        self.catching_up = events.len() as i64 == CATCH_UP_BATCH_SIZE;
+        self.stalled = self.item_id.is_some() && events.is_empty();
+        // 비공개 이벤트만 있는 구간도 다음 조회 위치를 넘기기 위해 위치 갱신
+        if let Some(last) = events.last() {
+            self.last_position = Some(last.position);
+        }
+        self.pending.extend(events);
+        Ok(())
+    }
+}
+
+// endregion: --- Event Stream
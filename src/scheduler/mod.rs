@@ -2,35 +2,54 @@
 /// 상품에 대한 상태를 관리하는 마이크로서비스는 별도로 있다 가정
 /// 상품 관리 마이크로 서비스는 경매 시작 시간과 종료 시간에 따른 상태 업데이트를 한다고 가정
 /// 다만 즉시 구매를 통해 낙찰이 되는 경우, 본 입찰 및 즉시구매 마이크로 서비스에서 완료 상태로 처리한다.
+/// 경매 시작 및 종료 시에는 AuctionStarted, AuctionClosed 이벤트를 이벤트 저장소에 기록한다. (종료 시 낙찰자 결정)
+/// 네덜란드식 경매는 가격 하락 일정에 따라 PriceDropped 이벤트를 기록한다.
 // region:    --- Imports
-use chrono::Utc;
-use sqlx::PgPool;
+use crate::auction::aggregate::AuctionAggregate;
+use crate::auction::events::AuctionEvent;
+use crate::database::DatabaseManager;
+use crate::error::AuctionError;
+use crate::event_store::{Event, EventStore, PostgresEventStore};
+use crate::query::repository::{ItemRepository, PostgresRepository};
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use tokio::time::{interval, Duration};
-use tracing::{debug, error};
+use tracing::{debug, error, info, warn};
 
 // endregion: --- Imports
 
 // region:    --- Auction Scheduler
 /// 경매 상태 업데이트 스케줄러
 pub struct AuctionScheduler {
-    pool: Arc<PgPool>,
+    db_manager: Arc<DatabaseManager>,
+    event_store: Arc<PostgresEventStore>,
+    repository: Arc<PostgresRepository>,
 }
 
 /// 경매 상태 업데이트 스케줄러 생성
 impl AuctionScheduler {
-    pub fn new(pool: Arc<PgPool>) -> Self {
-        Self { pool }
+    pub fn new(db_manager: Arc<DatabaseManager>) -> Self {
+        let event_store = Arc::new(PostgresEventStore::new(Arc::clone(&db_manager)));
+        let repository = Arc::new(PostgresRepository::new(Arc::clone(&db_manager)));
+        Self {
+            db_manager,
+            event_store,
+            repository,
+        }
     }
 
     /// 경매 상태 업데이트 스케줄러 시작
     pub async fn start(&self) {
-        let pool = Arc::clone(&self.pool);
+        let db_manager = Arc::clone(&self.db_manager);
+        let event_store = Arc::clone(&self.event_store);
+        let repository = Arc::clone(&self.repository);
         tokio::spawn(async move {
             let mut interval = interval(Duration::from_secs(1)); // 1초마다 실행
             loop {
                 interval.tick().await;
-                if let Err(e) = Self::update_auction_statuses(&pool).await {
+                if let Err(e) =
+                    Self::update_auction_statuses(&db_manager, &*event_store, &*repository).await
+                {
                     error!(
                         "{:<12} --> 경매 상태 업데이트 중 오류 발생: {:?}",
                         "Scheduler", e
@@ -41,27 +60,62 @@ impl AuctionScheduler {
     }
 
     /// 경매 상태 업데이트
-    async fn update_auction_statuses(pool: &PgPool) -> Result<(), sqlx::Error> {
+    async fn update_auction_statuses(
+        db_manager: &DatabaseManager,
+        event_store: &impl EventStore,
+        items: &impl ItemRepository,
+    ) -> Result<(), AuctionError> {
         let now = Utc::now();
 
-        // SCHEDULED -> ACTIVE 상태 변경
-        sqlx::query(
-            "UPDATE items SET status = 'ACTIVE' 
-             WHERE status = 'SCHEDULED' AND start_time <= $1",
+        // SCHEDULED -> ACTIVE 상태 변경 (AuctionStarted 이벤트 발행)
+        // 이미 시작 이벤트가 있으면(조회 모델 반영 대기 중) 제외
+        let item_ids = sqlx::query_scalar::<_, i64>(
+            "SELECT id FROM items
+             WHERE status = 'SCHEDULED' AND start_time <= $1
+             AND NOT EXISTS (
+                 SELECT 1 FROM events e
+                 WHERE e.aggregate_id = items.id AND e.event_type = 'AuctionStarted'
+             )",
         )
         .bind(now)
-        .execute(pool)
+        .fetch_all(db_manager.pool())
         .await?;
 
-        // ACTIVE -> COMPLETED 상태 변경
-        sqlx::query(
-            "UPDATE items SET status = 'COMPLETED' 
-             WHERE status = 'ACTIVE' AND end_time <= $1",
+        for item_id in item_ids {
+            if let Err(e) = Self::start_auction(event_store, items, item_id, now).await {
+                warn!("{:<12} --> 경매 시작 처리 실패: {}", "Scheduler", e);
+            }
+        }
+
+        // 네덜란드식 경매 가격 하락 (PriceDropped 이벤트 발행)
+        let item_ids = sqlx::query_scalar::<_, i64>(
+            "SELECT id FROM items WHERE status = 'ACTIVE' AND auction_type = 'DUTCH' AND end_time > $1",
+        )
+        .bind(now)
+        .fetch_all(db_manager.pool())
+        .await?;
+
+        for item_id in item_ids {
+            if let Err(e) = Self::drop_price(event_store, items, item_id, now).await {
+                warn!("{:<12} --> 가격 하락 처리 실패: {}", "Scheduler", e);
+            }
+        }
+
+        // ACTIVE -> COMPLETED 상태 변경 (AuctionClosed 이벤트 발행)
+        // 경매 연장 및 종료 여부는 조회 모델이 아닌 경매 애그리거트 기준으로 판단
+        let item_ids = sqlx::query_scalar::<_, i64>(
+            "SELECT id FROM items WHERE status = 'ACTIVE' AND end_time <= $1",
         )
         .bind(now)
-        .execute(pool)
+        .fetch_all(db_manager.pool())
         .await?;
 
+        for item_id in item_ids {
+            if let Err(e) = Self::close_auction(event_store, items, item_id, now).await {
+                warn!("{:<12} --> 경매 종료 처리 실패: {}", "Scheduler", e);
+            }
+        }
+
         debug!(
             "{:<12} --> 경매 상태가 성공적으로 업데이트되었습니다.",
             "Scheduler"
@@ -69,5 +123,131 @@ impl AuctionScheduler {
 
         Ok(())
     }
+
+    /// 경매 시작 처리
+    /// 조회 모델 반영 전에 철회되거나 취소된 경우 시작 이벤트를 기록하지 않음
+    /// 버전 충돌 시(시작 직전 수정 등) 다음 주기에 다시 처리
+    async fn start_auction(
+        event_store: &impl EventStore,
+        items: &impl ItemRepository,
+        item_id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<(), AuctionError> {
+        let aggregate = AuctionAggregate::load(event_store, items, item_id).await?;
+
+        // 이미 시작되었거나 철회, 취소되었거나 시작 시간이 변경된 경우 제외
+        if aggregate.status != "SCHEDULED" || aggregate.start_time > now {
+            return Ok(());
+        }
+
+        let started_event = AuctionEvent::AuctionStarted {
+            item_id,
+            timestamp: now,
+        };
+        Self::append_event(event_store, item_id, started_event, aggregate.version, now).await?;
+        info!("{:<12} --> 경매 시작: item_id={}", "Scheduler", item_id);
+        Ok(())
+    }
+
+    /// 네덜란드식 경매 가격 하락 처리
+    /// 가격은 경매 시작 시간 기준 일정으로 계산하므로, 스케줄러 실행이 늦어져도 일정상 가격으로 한 번에 하락
+    /// 버전 충돌 시(가격 수락 등) 다음 주기에 다시 처리
+    async fn drop_price(
+        event_store: &impl EventStore,
+        items: &impl ItemRepository,
+        item_id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<(), AuctionError> {
+        let aggregate = AuctionAggregate::load(event_store, items, item_id).await?;
+
+        // 이미 낙찰되었거나 종료 시간이 지난 경우 제외
+        if aggregate.status != "ACTIVE" || !aggregate.is_dutch() || aggregate.end_time <= now {
+            return Ok(());
+        }
+
+        let new_price = aggregate.scheduled_price(now);
+        if new_price >= aggregate.current_price {
+            return Ok(());
+        }
+
+        let dropped_event = AuctionEvent::PriceDropped {
+            item_id,
+            previous_price: aggregate.current_price,
+            new_price,
+            timestamp: now,
+        };
+        Self::append_event(event_store, item_id, dropped_event, aggregate.version, now).await?;
+        info!(
+            "{:<12} --> 가격 하락: item_id={}, {} -> {}",
+            "Scheduler", item_id, aggregate.current_price, new_price
+        );
+        Ok(())
+    }
+
+    /// 경매 종료 처리
+    /// 최고 입찰자를 낙찰자로 결정하며, 최저 낙찰가 미달 시 유찰 처리
+    /// 낙찰가는 최고 입찰 금액 (차가 비공개 입찰 경매는 두 번째로 높은 입찰 금액)
+    /// 버전 충돌 시(종료 직전 입찰 등) 다음 주기에 다시 처리
+    async fn close_auction(
+        event_store: &impl EventStore,
+        items: &impl ItemRepository,
+        item_id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<(), AuctionError> {
+        let aggregate = AuctionAggregate::load(event_store, items, item_id).await?;
+
+        // 이미 종료되었거나 경매가 연장된 경우 제외
+        if aggregate.status != "ACTIVE" || aggregate.end_time > now {
+            return Ok(());
+        }
+
+        let winning_bid = aggregate.highest_bid();
+        let reserve_met = match (aggregate.reserve_price, winning_bid) {
+            (None, _) => true,
+            (Some(reserve_price), Some(bid)) => bid.bid_amount >= reserve_price,
+            (Some(_), None) => false,
+        };
+        let (winner_id, final_price) = match winning_bid {
+            Some(bid) if reserve_met => (Some(bid.bidder_id), Some(aggregate.winning_price(bid))),
+            _ => (None, None),
+        };
+
+        let closed_event = AuctionEvent::AuctionClosed {
+            item_id,
+            winner_id,
+            final_price,
+            reserve_met,
+            timestamp: now,
+        };
+        Self::append_event(event_store, item_id, closed_event, aggregate.version, now).await?;
+        info!(
+            "{:<12} --> 경매 종료: item_id={}, 낙찰자={:?}, 낙찰가={:?}",
+            "Scheduler", item_id, winner_id, final_price
+        );
+        Ok(())
+    }
+
+    /// 상태 전환 이벤트 저장 및 발행
+    /// 입찰 명령과 동일하게 현재 버전 + 1로 저장하며, 버전 충돌 시 다음 주기에 다시 처리
+    async fn append_event(
+        event_store: &impl EventStore,
+        item_id: i64,
+        auction_event: AuctionEvent,
+        current_version: i64,
+        now: DateTime<Utc>,
+    ) -> Result<(), AuctionError> {
+        let event = Event {
+            id: 0,
+            aggregate_id: item_id,
+            event_type: auction_event.event_type().to_string(),
+            data: serde_json::to_value(auction_event)?,
+            timestamp: now,
+            version: current_version + 1,
+            idempotency_key: None,
+            position: 0,
+        };
+
+        event_store.append_and_publish_event(event).await
+    }
 }
 // endregion: --- Auction Scheduler
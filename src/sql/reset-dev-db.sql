@@ -0,0 +1,4 @@
+-- 개발용 데이터베이스 초기화 (RESET_DEV_DATABASE=true 로 실행 시에만 사용)
+-- public 스키마의 모든 테이블과 마이그레이션 이력을 삭제한 뒤 마이그레이션을 처음부터 다시 적용
+DROP SCHEMA IF EXISTS public CASCADE;
+CREATE SCHEMA public;
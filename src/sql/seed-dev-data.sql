@@ -0,0 +1,42 @@
+-- 개발용 샘플 데이터 (RESET_DEV_DATABASE=true 로 실행 시 초기화 후 삽입)
+INSERT INTO items (title, description, starting_price, current_price, buy_now_price, start_time, end_time, seller, status, created_at)
+VALUES (
+  '나이키 한정판 운동화', 
+  'ACTIVE 테스트 - 칸예 웨스트 콜라보레이션', 
+   10000,
+   10000,
+   50000,
+  NOW(),
+  NOW() + INTERVAL '10 minutes',
+  '일이삼', 
+  'ACTIVE',
+  CURRENT_TIMESTAMP
+);
+
+INSERT INTO items (title, description, starting_price, current_price, buy_now_price, start_time, end_time, seller, status, created_at)
+VALUES (
+  '기안84 무제', 
+  'SCHEDULED 테스트 - 유명 웹툰 작가이자 방송인, 2023년작', 
+  50000,
+  50000,
+  100000,
+  NOW() + INTERVAL '1 minutes',
+  NOW() + INTERVAL '3 minutes',
+  '버나스리', 
+  'SCHEDULED',
+  CURRENT_TIMESTAMP
+);
+
+INSERT INTO items (title, description, starting_price, current_price, buy_now_price, start_time, end_time, seller, status, created_at)
+VALUES (
+  '빈티지 스피커', 
+  'COMPLETED 테스트 - 탄노이 빈티지 스피커', 
+  70000,
+  70000,
+  200000,
+  NOW() - INTERVAL '10 minutes',
+  NOW() - INTERVAL '3 minutes',
+  '브라운김', 
+  'COMPLETED',
+  CURRENT_TIMESTAMP
+);
\ No newline at end of file
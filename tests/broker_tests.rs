@@ -0,0 +1,176 @@
+use async_trait::async_trait;
+use auction_service::event_store::Event;
+use auction_service::message_broker::{
+    self, dead_letter_topic, InMemoryBroker, MessageBroker, Subscription,
+};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// 인메모리 메시지 브로커 테스트
+// 토픽 채널 용량, 구독 종료 및 데드레터 전송 실패 처리
+
+/// 구독자가 없는 데드레터 토픽 발행 테스트
+/// 채널 용량을 넘어도 발행이 대기하지 않고 넘친 메시지는 버리며, 이후 구독하면 보관된 메시지부터 수신
+#[tokio::test]
+async fn test_dead_letter_publish_without_subscriber_is_bounded() {
+    let broker = InMemoryBroker::new();
+    let publish_all = async {
+        for i in 0..10_001 {
+            broker
+                .publish("events-dead-letter", "1", &i.to_string(), &[])
+                .await
+                .unwrap();
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(5), publish_all)
+        .await
+        .unwrap();
+
+    let mut subscription = broker.subscribe("events-dead-letter").await.unwrap();
+    let message = subscription.recv().await.unwrap().unwrap();
+    assert_eq!(message.offset, 0);
+    assert_eq!(message.payload.unwrap(), b"0");
+}
+
+/// 구독자가 없는 토픽 발행 실패 테스트
+/// 채널 용량을 넘으면 메시지를 버리지 않고 발행이 실패하며, 이후 구독하면 보관된 메시지부터 수신
+#[tokio::test]
+async fn test_publish_without_subscriber_fails_when_full() {
+    let broker = InMemoryBroker::new();
+    for i in 0..10_000 {
+        broker
+            .publish("events", "1", &i.to_string(), &[])
+            .await
+            .unwrap();
+    }
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        broker.publish("events", "1", "10000", &[]),
+    )
+    .await
+    .unwrap();
+    assert!(result.is_err());
+
+    let mut subscription = broker.subscribe("events").await.unwrap();
+    let message = subscription.recv().await.unwrap().unwrap();
+    assert_eq!(message.payload.unwrap(), b"0");
+}
+
+/// 구독 종료 테스트
+/// 브로커가 삭제되어 채널이 닫히면 보관된 메시지를 모두 수신한 뒤 None 반환
+#[tokio::test]
+async fn test_subscription_ends_when_broker_is_dropped() {
+    let broker = InMemoryBroker::new();
+    let mut subscription = broker.subscribe("events").await.unwrap();
+    broker.publish("events", "1", "payload", &[]).await.unwrap();
+    drop(broker);
+
+    let message = subscription.recv().await.unwrap().unwrap();
+    assert_eq!(message.key.as_deref(), Some("1"));
+    assert!(subscription.recv().await.unwrap().is_none());
+}
+
+/// 데드레터 전송 실패 테스트
+/// 데드레터 전송에 실패하면 성공할 때까지 재시도하며, 그동안 다음 메시지를 처리하지 않음
+#[tokio::test]
+async fn test_dead_letter_send_is_retried_before_next_message() {
+    let broker = Arc::new(FailingDeadLetterBroker {
+        inner: InMemoryBroker::new(),
+        failures: AtomicU32::new(2),
+    });
+    let event = Event {
+        id: 1,
+        aggregate_id: 1,
+        event_type: "BidPlaced".to_string(),
+        data: serde_json::json!({}),
+        timestamp: chrono::Utc::now(),
+        version: 1,
+        idempotency_key: None,
+        position: 1,
+    };
+    broker.publish("events", "1", "invalid", &[]).await.unwrap();
+    broker
+        .publish("events", "1", &serde_json::to_string(&event).unwrap(), &[])
+        .await
+        .unwrap();
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let consumer = tokio::spawn({
+        let broker = broker.clone();
+        let (handled, dead_lettered) = (log.clone(), log.clone());
+        async move {
+            message_broker::consume_events(
+                broker.as_ref(),
+                "events",
+                move |_| {
+                    handled.lock().unwrap().push("handled");
+                    async { Ok::<(), Box<dyn std::error::Error>>(()) }
+                },
+                move |_| {
+                    dead_lettered.lock().unwrap().push("dead_lettered");
+                    async {}
+                },
+            )
+            .await
+        }
+    });
+
+    let wait = async {
+        while log.lock().unwrap().len() < 2 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(5), wait)
+        .await
+        .unwrap();
+    consumer.abort();
+
+    assert_eq!(*log.lock().unwrap(), vec!["dead_lettered", "handled"]);
+    assert_eq!(broker.failures.load(Ordering::SeqCst), 0);
+    let mut dead_letters = broker.subscribe(&dead_letter_topic()).await.unwrap();
+    let message = dead_letters.recv().await.unwrap().unwrap();
+    assert_eq!(message.payload.unwrap(), b"invalid");
+}
+
+/// 데드레터 토픽 발행이 지정한 횟수만큼 실패하는 브로커
+struct FailingDeadLetterBroker {
+    inner: InMemoryBroker,
+    failures: AtomicU32,
+}
+
+#[async_trait]
+impl MessageBroker for FailingDeadLetterBroker {
+    async fn create_topic(
+        &self,
+        topic_name: &str,
+        num_partitions: i32,
+        replication_factor: i32,
+    ) -> Result<(), String> {
+        self.inner
+            .create_topic(topic_name, num_partitions, replication_factor)
+            .await
+    }
+
+    async fn publish(
+        &self,
+        topic: &str,
+        key: &str,
+        payload: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<(), String> {
+        let failing = topic == dead_letter_topic()
+            && self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+        if failing {
+            return Err("데드레터 토픽 발행 실패".to_string());
+        }
+        self.inner.publish(topic, key, payload, headers).await
+    }
+
+    async fn subscribe(&self, topic: &str) -> Result<Box<dyn Subscription>, String> {
+        self.inner.subscribe(topic).await
+    }
+}
@@ -0,0 +1,1081 @@
+use async_trait::async_trait;
+use auction_service::auction::aggregate::AuctionAggregate;
+use auction_service::auction::commands::{
+    handle_cancel_auction, handle_list_item, handle_update_item, handle_withdraw_item,
+    CancelAuctionCommand, ListItemCommand, UpdateItemCommand, WithdrawItemCommand,
+};
+use auction_service::auction::events::AuctionEvent;
+use auction_service::bidding::commands::{
+    handle_buy_now, handle_place_bid, handle_retract_bid, BuyNowCommand, PlaceBidCommand,
+    RetractBidCommand,
+};
+use auction_service::bidding::increment::IncrementStep;
+use auction_service::bidding::model::{Bid, Item};
+use auction_service::error::AuctionError;
+use auction_service::event_store::{Event, EventStore, InMemoryEventStore, Snapshot};
+use auction_service::query::memory::InMemoryRepository;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::types::Json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// 명령 처리 테스트
+// 인메모리 이벤트 저장소와 조회 모델 저장소를 사용하므로 서버, PostgreSQL, Kafka 없이 실행
+
+/// 입찰 처리 테스트
+#[tokio::test]
+async fn test_place_bid_appends_event() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    start_test_item(&event_store, &items, test_item(1, "ACTIVE")).await;
+
+    let aggregate = handle_place_bid(bid_command(1, 1, 11000), &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(aggregate.current_price, 11000);
+    assert_eq!(aggregate.version, 2);
+
+    let events = event_store.events();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[1].event_type, "BidPlaced");
+    assert_eq!(events[1].version, 2);
+}
+
+/// 현재 가격 이하 입찰 거절 테스트 (입찰이 없으면 시작 가격 미만만 거절)
+#[tokio::test]
+async fn test_low_bid_is_rejected() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    start_test_item(&event_store, &items, test_item(1, "ACTIVE")).await;
+
+    let error = handle_place_bid(bid_command(1, 1, 9999), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::LowBid { bid_amount: 9999 });
+    assert_eq!(event_store.events().len(), 1);
+
+    // 첫 입찰은 시작 가격과 같아도 가능, 이후 입찰은 현재 가격보다 높아야 함
+    let item = handle_place_bid(bid_command(1, 1, 10000), &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(item.current_price, 10000);
+
+    let error = handle_place_bid(bid_command(1, 2, 10000), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::LowBid { bid_amount: 10000 });
+    assert_eq!(event_store.events().len(), 2);
+}
+
+/// 시작 전 경매 입찰 거절 테스트
+#[tokio::test]
+async fn test_bid_on_scheduled_auction_is_rejected() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    items.insert_item(test_item(1, "SCHEDULED"));
+
+    let error = handle_place_bid(bid_command(1, 1, 11000), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::NotStarted);
+}
+
+/// 조회 모델로 시작하는 상품의 상태 재구성 테스트
+/// 상태 변경 이벤트가 없으면 조회 모델의 상태와 이벤트 도입 전 입찰로 시작하고, 연장된 종료 시간은 이벤트로 재적용
+#[tokio::test]
+async fn test_legacy_item_state_is_seeded_from_read_model() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    let mut item = test_item(1, "ACTIVE");
+    item.current_price = 15000;
+    item.end_time = Utc::now() + Duration::hours(3);
+    items.insert_item(item);
+    for (id, bidder_id, bid_amount) in [(1, 2, 12000), (2, 3, 15000)] {
+        items.insert_bid(Bid {
+            id,
+            item_id: 1,
+            bidder_id,
+            bid_amount,
+            bid_time: Utc::now() - Duration::minutes(10 - id),
+            is_auto: false,
+            retracted_at: None,
+        });
+    }
+
+    // 진행 중 상태, 현재 가격, 최고 입찰자를 조회 모델에서 복원
+    let aggregate = AuctionAggregate::load(&event_store, &items, 1)
+        .await
+        .unwrap();
+    assert_eq!(aggregate.status, "ACTIVE");
+    assert_eq!(aggregate.current_price, 15000);
+    assert_eq!(aggregate.highest_bid().unwrap().bidder_id, 3);
+    let error = handle_place_bid(bid_command(1, 1, 15000), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::LowBid { bid_amount: 15000 });
+    let aggregate = handle_place_bid(bid_command(1, 1, 16000), &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(aggregate.version, 1);
+    assert_eq!(aggregate.highest_bid().unwrap().bidder_id, 1);
+
+    // 이벤트 도입 전 입찰 철회 시 남은 입찰로 현재 가격 재계산
+    handle_retract_bid(retract_command(1, 1), &event_store, &items)
+        .await
+        .unwrap();
+    let aggregate = handle_retract_bid(retract_command(1, 3), &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(aggregate.current_price, 12000);
+    assert_eq!(aggregate.highest_bid().unwrap().bidder_id, 2);
+
+    // 종료된 상품은 종료 상태로 복원
+    items.insert_item(test_item(2, "COMPLETED"));
+    let error = handle_place_bid(bid_command(2, 1, 11000), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::AlreadyEnded);
+
+    // 연장 이벤트가 있으면 첫 연장 전 종료 시간부터 재적용
+    let mut item = test_item(3, "ACTIVE");
+    item.end_time += Duration::minutes(10);
+    let end_time = item.end_time;
+    items.insert_item(item);
+    let extended = AuctionEvent::AuctionExtended {
+        item_id: 3,
+        previous_end_time: end_time - Duration::minutes(10),
+        new_end_time: end_time - Duration::minutes(5),
+        timestamp: Utc::now(),
+    };
+    append_test_event(&event_store, 3, extended, 1, Utc::now()).await;
+
+    let aggregate = AuctionAggregate::load(&event_store, &items, 3)
+        .await
+        .unwrap();
+    assert_eq!(aggregate.status, "ACTIVE");
+    assert_eq!(aggregate.end_time, end_time - Duration::minutes(5));
+
+    // 상태 변경 이벤트가 있으면 시작 전 상태에서 이벤트로 재구성
+    let cancelled = AuctionEvent::AuctionCancelled {
+        item_id: 3,
+        reason: "테스트".to_string(),
+        bidder_ids: Vec::new(),
+        timestamp: Utc::now(),
+    };
+    append_test_event(&event_store, 3, cancelled, 2, Utc::now()).await;
+    let aggregate = AuctionAggregate::load(&event_store, &items, 3)
+        .await
+        .unwrap();
+    assert_eq!(aggregate.status, "CANCELLED");
+}
+
+/// 자동 입찰 동일 한도 테스트
+/// 최대 입찰가가 같으면 먼저 등록한 자동 입찰이 한도 금액으로 우선하며, 현재 가격을 올리는 입찰만 기록
+#[tokio::test]
+async fn test_proxy_bid_tie() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    start_test_item(&event_store, &items, test_item(1, "ACTIVE")).await;
+    start_test_item(&event_store, &items, test_item(2, "ACTIVE")).await;
+
+    let mut cmd = bid_command(1, 1, 11000);
+    cmd.max_bid = Some(20000);
+    handle_place_bid(cmd, &event_store, &items).await.unwrap();
+
+    // 나중에 같은 한도로 등록하면 먼저 등록한 입찰자가 한도 금액으로 자동 입찰
+    let mut cmd = bid_command(1, 2, 12000);
+    cmd.max_bid = Some(20000);
+    let aggregate = handle_place_bid(cmd, &event_store, &items).await.unwrap();
+    assert_eq!(aggregate.current_price, 20000);
+    assert_eq!(aggregate.bids.last().unwrap().bidder_id, 1);
+
+    let event_types: Vec<String> = event_store
+        .events()
+        .into_iter()
+        .filter(|event| event.aggregate_id == 1)
+        .map(|event| event.event_type)
+        .collect();
+    assert_eq!(
+        event_types,
+        [
+            "AuctionStarted",
+            "ProxyBidRegistered",
+            "BidPlaced",
+            "ProxyBidRegistered",
+            "BidPlaced",
+            "AutoBidPlaced"
+        ]
+    );
+
+    // 경쟁자 한도와 같은 금액으로 입찰하면 먼저 등록한 경쟁자가 한도 금액으로 우선 (입찰은 가격을 올리지 못하므로 기록하지 않음)
+    let mut cmd = bid_command(2, 1, 11000);
+    cmd.max_bid = Some(15000);
+    handle_place_bid(cmd, &event_store, &items).await.unwrap();
+
+    let aggregate = handle_place_bid(bid_command(2, 2, 15000), &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(aggregate.current_price, 15000);
+    assert_eq!(aggregate.bids.last().unwrap().bidder_id, 1);
+    let last_event = event_store.events().pop().unwrap();
+    assert_eq!(last_event.event_type, "AutoBidPlaced");
+    assert_eq!(last_event.data["AutoBidPlaced"]["bidder_id"], 1);
+    assert_eq!(last_event.data["AutoBidPlaced"]["bid_amount"], 15000);
+}
+
+/// 즉시 구매 테스트
+#[tokio::test]
+async fn test_buy_now_completes_auction() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    start_test_item(&event_store, &items, test_item(1, "ACTIVE")).await;
+
+    let cmd = BuyNowCommand {
+        item_id: 1,
+        buyer_id: 2,
+        idempotency_key: None,
+    };
+    let aggregate = handle_buy_now(cmd, &event_store, &items).await.unwrap();
+    assert_eq!(aggregate.status, "COMPLETED");
+    assert_eq!(aggregate.winner_id, Some(2));
+    assert_eq!(aggregate.current_price, 50000);
+
+    // 종료된 경매에는 입찰 불가
+    let error = handle_place_bid(bid_command(1, 1, 11000), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::AlreadyEnded);
+}
+
+/// 버전 충돌 재시도 테스트
+/// 첫 저장 직전에 다른 입찰이 같은 버전으로 저장되면, 애그리거트를 다시 재구성하여 다음 버전으로 저장
+#[tokio::test]
+async fn test_place_bid_retries_on_version_conflict() {
+    let event_store = ConflictingEventStore::new(1);
+    let items = InMemoryRepository::new();
+    start_test_item(&event_store.inner, &items, test_item(1, "ACTIVE")).await;
+
+    let aggregate = handle_place_bid(bid_command(1, 1, 13000), &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(event_store.conflicts.load(Ordering::SeqCst), 1);
+    assert_eq!(aggregate.version, 3);
+    assert_eq!(aggregate.current_price, 13000);
+
+    let events = event_store.inner.events();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[2].version, 3);
+    assert_eq!(events[2].data["BidPlaced"]["bidder_id"], 1);
+}
+
+/// 재시도 후에도 현재 가격 검증 테스트
+/// 충돌한 입찰이 더 높은 금액이면 재구성한 상태 기준으로 입찰 거절
+#[tokio::test]
+async fn test_place_bid_revalidates_after_version_conflict() {
+    let event_store = ConflictingEventStore::new(1);
+    let items = InMemoryRepository::new();
+    start_test_item(&event_store.inner, &items, test_item(1, "ACTIVE")).await;
+
+    let error = handle_place_bid(bid_command(1, 1, 11000), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::LowBid { bid_amount: 11000 });
+    assert_eq!(event_store.inner.events().len(), 2);
+}
+
+/// 최대 재시도 횟수 초과 테스트
+#[tokio::test]
+async fn test_place_bid_gives_up_after_max_retries() {
+    let event_store = ConflictingEventStore::new(usize::MAX);
+    let items = InMemoryRepository::new();
+    start_test_item(&event_store.inner, &items, test_item(1, "ACTIVE")).await;
+
+    let error = handle_place_bid(bid_command(1, 1, 1_000_000_000), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::MaxRetriesExceeded);
+}
+
+/// 상품 등록 및 수정 테스트
+/// 등록한 상품은 조회 모델 없이 이벤트만으로 애그리거트를 재구성
+#[tokio::test]
+async fn test_list_and_update_item() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+
+    let listed = handle_list_item(list_command(), &event_store)
+        .await
+        .unwrap();
+    assert_eq!(listed.status, "SCHEDULED");
+    assert_eq!(listed.version, 1);
+    assert_eq!(listed.current_price, 10000);
+
+    let cmd = UpdateItemCommand {
+        item_id: listed.item_id,
+        seller: "테스트 판매자".to_string(),
+        title: Some("수정된 상품".to_string()),
+        starting_price: Some(20000),
+        reserve_price: Some(Some(30000)),
+        ..Default::default()
+    };
+    let updated = handle_update_item(cmd, &event_store, &items).await.unwrap();
+    assert_eq!(updated.version, 2);
+    assert_eq!(updated.title, "수정된 상품");
+    assert_eq!(updated.current_price, 20000);
+    assert_eq!(updated.buy_now_price, 50000);
+    assert_eq!(updated.reserve_price, Some(30000));
+
+    // 명시적 null은 해제, 항목 누락은 유지
+    let cmd: UpdateItemCommand =
+        serde_json::from_value(serde_json::json!({ "reserve_price": null })).unwrap();
+    let cmd = UpdateItemCommand {
+        item_id: listed.item_id,
+        seller: "테스트 판매자".to_string(),
+        ..cmd
+    };
+    assert_eq!(cmd.reserve_price, Some(None));
+    assert_eq!(cmd.bid_increments, None);
+    let updated = handle_update_item(cmd, &event_store, &items).await.unwrap();
+    assert_eq!(updated.reserve_price, None);
+    assert_eq!(updated.title, "수정된 상품");
+
+    // 판매자가 아니면 수정 불가
+    let cmd = UpdateItemCommand {
+        item_id: listed.item_id,
+        seller: "다른 판매자".to_string(),
+        title: Some("다른 판매자의 수정".to_string()),
+        ..Default::default()
+    };
+    let error = handle_update_item(cmd, &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::NotItemSeller);
+
+    let events = event_store.events();
+    assert_eq!(events[0].event_type, "ItemListed");
+    assert_eq!(events[1].event_type, "ItemUpdated");
+    assert_eq!(events[1].data["ItemUpdated"]["description"], "명령 테스트");
+}
+
+/// 상품 등록 정보 검증 테스트
+#[tokio::test]
+async fn test_invalid_listing_is_rejected() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+
+    let mut cmd = list_command();
+    cmd.buy_now_price = 5000;
+    let error = handle_list_item(cmd, &event_store).await.unwrap_err();
+    assert_eq!(
+        error,
+        AuctionError::InvalidItem("item.buy_now_below_starting_price")
+    );
+
+    // 입찰 단위 구간은 가격 상한 오름차순
+    let mut cmd = list_command();
+    cmd.bid_increments = Some(vec![
+        IncrementStep {
+            below: Some(100_000),
+            increment: 1_000,
+        },
+        IncrementStep {
+            below: Some(10_000),
+            increment: 500,
+        },
+    ]);
+    let error = handle_list_item(cmd, &event_store).await.unwrap_err();
+    assert_eq!(
+        error,
+        AuctionError::InvalidItem("item.invalid_bid_increments")
+    );
+
+    let mut cmd = list_command();
+    cmd.end_time = cmd.start_time;
+    let error = handle_list_item(cmd, &event_store).await.unwrap_err();
+    assert_eq!(error, AuctionError::InvalidItem("item.end_before_start"));
+    assert!(event_store.events().is_empty());
+
+    // 수정 후 값 기준으로 검증
+    let listed = handle_list_item(list_command(), &event_store)
+        .await
+        .unwrap();
+    let cmd = UpdateItemCommand {
+        item_id: listed.item_id,
+        seller: "테스트 판매자".to_string(),
+        starting_price: Some(60000),
+        ..Default::default()
+    };
+    let error = handle_update_item(cmd, &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error,
+        AuctionError::InvalidItem("item.buy_now_below_starting_price")
+    );
+}
+
+/// 상품 철회 테스트
+/// 철회한 상품은 수정 및 입찰 불가, 시작된 경매는 철회 불가
+#[tokio::test]
+async fn test_withdraw_item() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+
+    let listed = handle_list_item(list_command(), &event_store)
+        .await
+        .unwrap();
+    let cmd = WithdrawItemCommand {
+        item_id: listed.item_id,
+        seller: "다른 판매자".to_string(),
+    };
+    let error = handle_withdraw_item(cmd, &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::NotItemSeller);
+
+    let cmd = WithdrawItemCommand {
+        item_id: listed.item_id,
+        seller: "테스트 판매자".to_string(),
+    };
+    let withdrawn = handle_withdraw_item(cmd, &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(withdrawn.status, "WITHDRAWN");
+
+    let cmd = UpdateItemCommand {
+        item_id: listed.item_id,
+        seller: "테스트 판매자".to_string(),
+        title: Some("철회 후 수정".to_string()),
+        ..Default::default()
+    };
+    let error = handle_update_item(cmd, &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::InvalidStatus("WITHDRAWN".to_string()));
+
+    let error = handle_place_bid(bid_command(listed.item_id, 1, 11000), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::InvalidStatus("WITHDRAWN".to_string()));
+
+    start_test_item(&event_store, &items, test_item(100, "ACTIVE")).await;
+    let cmd = WithdrawItemCommand {
+        item_id: 100,
+        seller: "테스트 판매자".to_string(),
+    };
+    let error = handle_withdraw_item(cmd, &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::InvalidStatus("ACTIVE".to_string()));
+}
+
+/// 경매 취소 테스트
+/// 취소 이벤트에 입찰자 id를 중복 없이 기록하고, 취소된 경매는 입찰 및 즉시 구매 불가
+#[tokio::test]
+async fn test_cancel_auction() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    start_test_item(&event_store, &items, test_item(1, "ACTIVE")).await;
+
+    for (bidder_id, bid_amount) in [(2, 11000), (1, 12000), (2, 13000)] {
+        handle_place_bid(bid_command(1, bidder_id, bid_amount), &event_store, &items)
+            .await
+            .unwrap();
+    }
+
+    // 사유 필수
+    let cmd = CancelAuctionCommand {
+        item_id: 1,
+        seller: "테스트 판매자".to_string(),
+        reason: " ".to_string(),
+    };
+    let error = handle_cancel_auction(cmd, &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::CancelReasonRequired);
+
+    // 판매자가 아니면 거절
+    let cmd = CancelAuctionCommand {
+        item_id: 1,
+        seller: "다른 판매자".to_string(),
+        reason: "상품 분실".to_string(),
+    };
+    let error = handle_cancel_auction(cmd, &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::NotItemSeller);
+
+    let cmd = CancelAuctionCommand {
+        item_id: 1,
+        seller: "테스트 판매자".to_string(),
+        reason: "상품 분실".to_string(),
+    };
+    let cancelled = handle_cancel_auction(cmd, &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(cancelled.status, "CANCELLED");
+
+    let events = event_store.events();
+    let cancelled_event = &events.last().unwrap().data["AuctionCancelled"];
+    assert_eq!(cancelled_event["reason"], "상품 분실");
+    assert_eq!(cancelled_event["bidder_ids"], serde_json::json!([1, 2]));
+
+    let error = handle_place_bid(bid_command(1, 3, 20000), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::InvalidStatus("CANCELLED".to_string()));
+    let cmd = BuyNowCommand {
+        item_id: 1,
+        buyer_id: 3,
+        idempotency_key: None,
+    };
+    let error = handle_buy_now(cmd, &event_store, &items).await.unwrap_err();
+    assert_eq!(error, AuctionError::InvalidStatus("CANCELLED".to_string()));
+}
+
+/// 입찰 철회 테스트 (기본 정책: 입찰 후 10분 이내, 경매 종료 1시간 전까지)
+#[tokio::test]
+async fn test_retract_bid() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    let mut item = test_item(1, "ACTIVE");
+    item.end_time = Utc::now() + Duration::hours(3);
+    start_test_item(&event_store, &items, item).await;
+
+    for (bidder_id, bid_amount) in [(2, 11000), (1, 12000), (2, 13000)] {
+        handle_place_bid(bid_command(1, bidder_id, bid_amount), &event_store, &items)
+            .await
+            .unwrap();
+    }
+
+    // 최근 입찰 철회 후 남은 입찰 중 최고 금액으로 현재 가격 재계산
+    let retracted = handle_retract_bid(retract_command(1, 2), &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(retracted.current_price, 12000);
+    assert_eq!(retracted.bids.len(), 2);
+
+    let events = event_store.events();
+    let retracted_event = &events.last().unwrap().data["BidRetracted"];
+    assert_eq!(retracted_event["bid_amount"], 13000);
+    assert_eq!(retracted_event["bid_version"], 4);
+
+    // 입찰하지 않은 입찰자
+    let error = handle_retract_bid(retract_command(1, 3), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error,
+        AuctionError::RetractionNotAllowed("retraction.no_bid")
+    );
+
+    // 철회 가능 시간이 지난 입찰
+    let bid_time = Utc::now() - Duration::minutes(20);
+    let old_bid = AuctionEvent::BidPlaced {
+        item_id: 1,
+        bidder_id: 3,
+        bid_amount: 14000,
+        timestamp: bid_time,
+    };
+    let version = events.last().unwrap().version + 1;
+    append_test_event(&event_store, 1, old_bid, version, bid_time).await;
+    let error = handle_retract_bid(retract_command(1, 3), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error,
+        AuctionError::RetractionNotAllowed("retraction.window_expired")
+    );
+
+    // 경매 종료 1시간 전부터는 철회 불가
+    start_test_item(&event_store, &items, test_item(2, "ACTIVE")).await;
+    handle_place_bid(bid_command(2, 1, 11000), &event_store, &items)
+        .await
+        .unwrap();
+    let error = handle_retract_bid(retract_command(2, 1), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error,
+        AuctionError::RetractionNotAllowed("retraction.closing_soon")
+    );
+}
+
+/// 입찰 철회 후 자동 입찰 재경쟁 테스트
+/// 철회 후 최고 입찰자가 아닌 입찰자의 자동 입찰 한도가 현재 가격보다 높으면 다음 입찰 가능 금액으로 자동 입찰
+#[tokio::test]
+async fn test_retract_bid_reruns_proxy_bidding() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    let mut item = test_item(1, "ACTIVE");
+    item.end_time = Utc::now() + Duration::hours(3);
+    item.bid_increments = Some(Json(vec![IncrementStep {
+        below: None,
+        increment: 500,
+    }]));
+    start_test_item(&event_store, &items, item).await;
+
+    // 자동 입찰 한도(15,000원)보다 낮은 다른 입찰자의 입찰만 남도록 이벤트 기록
+    let now = Utc::now();
+    let events = [
+        AuctionEvent::ProxyBidRegistered {
+            item_id: 1,
+            bidder_id: 1,
+            max_amount: 15000,
+            timestamp: now,
+        },
+        AuctionEvent::BidPlaced {
+            item_id: 1,
+            bidder_id: 1,
+            bid_amount: 11000,
+            timestamp: now,
+        },
+        AuctionEvent::BidPlaced {
+            item_id: 1,
+            bidder_id: 3,
+            bid_amount: 12000,
+            timestamp: now,
+        },
+        AuctionEvent::BidPlaced {
+            item_id: 1,
+            bidder_id: 2,
+            bid_amount: 16000,
+            timestamp: now,
+        },
+    ];
+    for (auction_event, version) in events.into_iter().zip(2..) {
+        append_test_event(&event_store, 1, auction_event, version, now).await;
+    }
+
+    let retracted = handle_retract_bid(retract_command(1, 2), &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(retracted.current_price, 12500);
+    assert_eq!(retracted.highest_bid().unwrap().bidder_id, 1);
+
+    let events = event_store.events();
+    let event_types: Vec<&str> = events[5..]
+        .iter()
+        .map(|event| event.event_type.as_str())
+        .collect();
+    assert_eq!(event_types, ["BidRetracted", "AutoBidPlaced"]);
+    assert_eq!(events[6].data["AutoBidPlaced"]["bidder_id"], 1);
+    assert_eq!(events[6].version, 7);
+
+    // 자동 입찰 한도보다 높은 입찰만 남으면 자동 입찰하지 않음
+    handle_place_bid(bid_command(1, 3, 16000), &event_store, &items)
+        .await
+        .unwrap();
+    handle_place_bid(bid_command(1, 2, 17000), &event_store, &items)
+        .await
+        .unwrap();
+    let retracted = handle_retract_bid(retract_command(1, 2), &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(retracted.current_price, 16000);
+    assert_eq!(
+        event_store.events().last().unwrap().event_type,
+        "BidRetracted"
+    );
+}
+
+/// 최저 낙찰가 충족 여부 테스트
+/// 현재 가격이 아닌 유효한 최고 입찰 기준 (입찰이 없으면 최저 낙찰가가 시작 가격과 같아도 미충족), 비공개 입찰 경매는 종료 전까지 미충족
+#[tokio::test]
+async fn test_reserve_met_requires_a_bid() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    let mut item = test_item(1, "ACTIVE");
+    item.reserve_price = Some(10000);
+    item.end_time = Utc::now() + Duration::hours(3);
+    start_test_item(&event_store, &items, item.clone()).await;
+
+    let aggregate = AuctionAggregate::load(&event_store, &items, 1)
+        .await
+        .unwrap();
+    assert!(!aggregate.is_reserve_met());
+
+    let aggregate = handle_place_bid(bid_command(1, 2, 10000), &event_store, &items)
+        .await
+        .unwrap();
+    assert!(aggregate.is_reserve_met());
+
+    // 철회로 유효한 입찰이 없어지면 다시 미충족
+    let aggregate = handle_retract_bid(retract_command(1, 2), &event_store, &items)
+        .await
+        .unwrap();
+    assert!(!aggregate.is_reserve_met());
+
+    // 비공개 입찰 경매
+    let mut sealed = item;
+    sealed.id = 2;
+    sealed.auction_type = "SEALED_FIRST_PRICE".to_string();
+    start_test_item(&event_store, &items, sealed).await;
+    let aggregate = handle_place_bid(bid_command(2, 2, 20000), &event_store, &items)
+        .await
+        .unwrap();
+    assert!(!aggregate.is_reserve_met());
+}
+
+/// 네덜란드식(가격 하락식) 경매 테스트
+/// 가격 하락 일정 및 하한, 일정상 가격 이상 입찰 시 일정상 가격으로 낙찰, 자동 입찰 거절, 즉시 구매 가격 미사용
+#[tokio::test]
+async fn test_dutch_auction() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    let mut item = test_item(1, "ACTIVE");
+    item.auction_type = "DUTCH".to_string();
+    item.price_drop_amount = 1000;
+    item.price_drop_interval_secs = 1200;
+    item.reserve_price = Some(5000);
+    start_test_item(&event_store, &items, item.clone()).await;
+    let mut buy_now_item = item.clone();
+    buy_now_item.id = 2;
+    start_test_item(&event_store, &items, buy_now_item).await;
+
+    // 시작 후 20분마다 1,000원 하락, 최저 낙찰가(없으면 1원) 아래로는 하락하지 않음
+    let mut aggregate = AuctionAggregate::from_item(&item);
+    assert_eq!(
+        aggregate.scheduled_price(item.start_time + Duration::minutes(25)),
+        9000
+    );
+    assert_eq!(
+        aggregate.scheduled_price(item.start_time + Duration::hours(2)),
+        5000
+    );
+    aggregate.reserve_price = None;
+    assert_eq!(
+        aggregate.scheduled_price(item.start_time + Duration::hours(5)),
+        1
+    );
+
+    // 스케줄러가 가격 하락 이벤트를 한 단계 늦게 기록한 상태 (일정상 가격은 시작 1시간 후 7,000원)
+    let dropped = AuctionEvent::PriceDropped {
+        item_id: 1,
+        previous_price: 10000,
+        new_price: 8000,
+        timestamp: Utc::now(),
+    };
+    append_test_event(&event_store, 1, dropped, 2, Utc::now()).await;
+
+    let mut cmd = bid_command(1, 2, 8000);
+    cmd.max_bid = Some(9000);
+    let error = handle_place_bid(cmd, &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::ProxyBidNotSupported);
+
+    let error = handle_place_bid(bid_command(1, 2, 6500), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::LowBid { bid_amount: 6500 });
+
+    // 일정상 가격 이상 입찰 시 기록된 현재 가격 대신 일정상 가격으로 낙찰
+    let accepted = handle_place_bid(bid_command(1, 2, 7000), &event_store, &items)
+        .await
+        .unwrap();
+    assert_eq!(accepted.status, "COMPLETED");
+    assert_eq!(accepted.winner_id, Some(2));
+    assert_eq!(accepted.current_price, 7000);
+
+    let error = handle_place_bid(bid_command(1, 3, 7000), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::AlreadyEnded);
+
+    // 즉시 구매도 일정상 가격으로 낙찰
+    let cmd = BuyNowCommand {
+        item_id: 2,
+        buyer_id: 3,
+        idempotency_key: None,
+    };
+    let bought = handle_buy_now(cmd, &event_store, &items).await.unwrap();
+    assert_eq!(bought.winner_id, Some(3));
+    assert_eq!(bought.current_price, 7000);
+
+    // 가격 하락 주기 누락
+    let mut cmd = list_command();
+    cmd.auction_type = "DUTCH".to_string();
+    cmd.price_drop_amount = 1000;
+    let error = handle_list_item(cmd, &event_store).await.unwrap_err();
+    assert_eq!(error, AuctionError::InvalidItem("item.invalid_price_drop"));
+
+    // 즉시 구매 가격은 사용하지 않으므로 시작 가격보다 낮아도 등록
+    let mut cmd = list_command();
+    cmd.auction_type = "DUTCH".to_string();
+    cmd.price_drop_amount = 1000;
+    cmd.price_drop_interval_secs = 600;
+    cmd.buy_now_price = 0;
+    handle_list_item(cmd, &event_store).await.unwrap();
+}
+
+/// 비공개 입찰 경매 테스트
+/// 입찰자별 하나의 입찰만 유지(다시 제출 시 대체), 종료 전 현재 가격 비공개, 낙찰가 계산
+#[tokio::test]
+async fn test_sealed_bid_auction() {
+    let event_store = InMemoryEventStore::new();
+    let items = InMemoryRepository::new();
+    let mut item = test_item(1, "ACTIVE");
+    item.auction_type = "SEALED_SECOND_PRICE".to_string();
+    start_test_item(&event_store, &items, item).await;
+
+    let error = handle_place_bid(bid_command(1, 2, 9000), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::LowBid { bid_amount: 9000 });
+
+    let mut cmd = bid_command(1, 2, 15000);
+    cmd.max_bid = Some(20000);
+    let error = handle_place_bid(cmd, &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(error, AuctionError::ProxyBidNotSupported);
+
+    // 현재 가격보다 낮은 금액으로도 다시 제출 가능
+    for (bidder_id, bid_amount) in [(2, 15000), (3, 30000), (2, 20000), (4, 12000)] {
+        handle_place_bid(bid_command(1, bidder_id, bid_amount), &event_store, &items)
+            .await
+            .unwrap();
+    }
+    let aggregate = AuctionAggregate::load(&event_store, &items, 1)
+        .await
+        .unwrap();
+    assert_eq!(aggregate.current_price, 10000);
+    assert_eq!(aggregate.bids.len(), 3);
+    assert_eq!(aggregate.latest_bid_of(2).unwrap().bid_amount, 20000);
+
+    // 차가 낙찰은 두 번째로 높은 입찰 금액, 최고가 낙찰은 최고 입찰 금액
+    let winning_bid = aggregate.highest_bid().unwrap();
+    assert_eq!(winning_bid.bidder_id, 3);
+    assert_eq!(aggregate.winning_price(winning_bid), 20000);
+    let mut first_price = aggregate.clone();
+    first_price.auction_type = "SEALED_FIRST_PRICE".to_string();
+    assert_eq!(first_price.winning_price(winning_bid), 30000);
+
+    let cmd = BuyNowCommand {
+        item_id: 1,
+        buyer_id: 5,
+        idempotency_key: None,
+    };
+    let error = handle_buy_now(cmd, &event_store, &items).await.unwrap_err();
+    assert_eq!(error, AuctionError::BuyNowNotSupported);
+
+    let error = handle_retract_bid(retract_command(1, 2), &event_store, &items)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error,
+        AuctionError::RetractionNotAllowed("retraction.sealed_bid")
+    );
+}
+
+/// 상품 등록 명령 생성 (1시간 후 시작, 시작 가격 10,000원, 즉시 구매 가격 50,000원)
+fn list_command() -> ListItemCommand {
+    let start_time = Utc::now() + Duration::hours(1);
+    ListItemCommand {
+        title: "등록 테스트 상품".to_string(),
+        description: "명령 테스트".to_string(),
+        seller: "테스트 판매자".to_string(),
+        starting_price: 10000,
+        buy_now_price: 50000,
+        reserve_price: None,
+        bid_increments: None,
+        start_time,
+        end_time: start_time + Duration::hours(2),
+        soft_close_window_secs: 0,
+        soft_close_extension_secs: 0,
+        auction_type: "ENGLISH".to_string(),
+        price_drop_amount: 0,
+        price_drop_interval_secs: 0,
+        idempotency_key: None,
+    }
+}
+
+/// 테스트용 상품 생성 (시작 가격 10,000원, 즉시 구매 가격 50,000원)
+fn test_item(id: i64, status: &str) -> Item {
+    let now = Utc::now();
+    let start_time = match status {
+        "SCHEDULED" => now + Duration::hours(1),
+        _ => now - Duration::hours(1),
+    };
+    Item {
+        id,
+        title: "명령 테스트 아이템".to_string(),
+        description: "명령 처리 테스트를 위한 아이템입니다.".to_string(),
+        starting_price: 10000,
+        current_price: 10000,
+        buy_now_price: 50000,
+        reserve_price: None,
+        reserve_met: true,
+        bid_increments: None,
+        start_time,
+        end_time: start_time + Duration::hours(2),
+        soft_close_window_secs: 0,
+        soft_close_extension_secs: 0,
+        auction_type: "ENGLISH".to_string(),
+        price_drop_amount: 0,
+        price_drop_interval_secs: 0,
+        seller: "테스트 판매자".to_string(),
+        status: status.to_string(),
+        winner_id: None,
+        created_at: now,
+    }
+}
+
+/// 입찰 명령 생성
+fn bid_command(item_id: i64, bidder_id: i64, bid_amount: i64) -> PlaceBidCommand {
+    PlaceBidCommand {
+        item_id,
+        bidder_id,
+        bid_amount,
+        max_bid: None,
+        idempotency_key: None,
+    }
+}
+
+/// 입찰 철회 명령 생성
+fn retract_command(item_id: i64, bidder_id: i64) -> RetractBidCommand {
+    RetractBidCommand {
+        item_id,
+        bidder_id,
+        idempotency_key: None,
+    }
+}
+
+/// 진행 중인 테스트용 상품 등록
+/// 조회 모델에 상품을 기록하고, 스케줄러와 같이 경매 시작 이벤트(version 1) 기록 (애그리거트는 시작 전 상태에서 이벤트로 재구성)
+async fn start_test_item(event_store: &impl EventStore, items: &InMemoryRepository, item: Item) {
+    let started = AuctionEvent::AuctionStarted {
+        item_id: item.id,
+        timestamp: item.start_time,
+    };
+    let event = Event {
+        id: 0,
+        aggregate_id: item.id,
+        event_type: started.event_type().to_string(),
+        data: serde_json::to_value(started).unwrap(),
+        timestamp: item.start_time,
+        version: 1,
+        idempotency_key: None,
+        position: 0,
+    };
+    items.insert_item(item);
+    event_store.append_and_publish_event(event).await.unwrap();
+}
+
+/// 이벤트 저장소에 이벤트 직접 기록 (스케줄러 이벤트 또는 지난 시각의 입찰 재현)
+async fn append_test_event(
+    event_store: &InMemoryEventStore,
+    item_id: i64,
+    auction_event: AuctionEvent,
+    version: i64,
+    timestamp: DateTime<Utc>,
+) {
+    let event = Event {
+        id: 0,
+        aggregate_id: item_id,
+        event_type: auction_event.event_type().to_string(),
+        data: serde_json::to_value(auction_event).unwrap(),
+        timestamp,
+        version,
+        idempotency_key: None,
+        position: 0,
+    };
+    event_store.append_and_publish_event(event).await.unwrap();
+}
+
+/// 저장 직전에 경쟁 입찰을 같은 버전으로 먼저 저장하여 버전 충돌을 재현하는 이벤트 저장소
+/// 경쟁 입찰은 입찰자 99가 현재 가격보다 1,000원 높게 입찰
+struct ConflictingEventStore {
+    inner: InMemoryEventStore,
+    remaining: AtomicUsize,
+    conflicts: AtomicUsize,
+}
+
+impl ConflictingEventStore {
+    fn new(conflicts: usize) -> Self {
+        Self {
+            inner: InMemoryEventStore::new(),
+            remaining: AtomicUsize::new(conflicts),
+            conflicts: AtomicUsize::new(0),
+        }
+    }
+
+    /// 경쟁 입찰 저장
+    async fn append_rival_bid(&self, events: &[Event]) -> Result<(), AuctionError> {
+        let Some(first) = events.first() else {
+            return Ok(());
+        };
+        let current_price = self
+            .inner
+            .load_events(first.aggregate_id, 0)
+            .await?
+            .iter()
+            .filter_map(|event| event.data["BidPlaced"]["bid_amount"].as_i64())
+            .fold(10000, i64::max);
+        let rival_bid = AuctionEvent::BidPlaced {
+            item_id: first.aggregate_id,
+            bidder_id: 99,
+            bid_amount: current_price + 1000,
+            timestamp: Utc::now(),
+        };
+        let event = Event {
+            id: 0,
+            aggregate_id: first.aggregate_id,
+            event_type: rival_bid.event_type().to_string(),
+            data: serde_json::to_value(rival_bid)?,
+            timestamp: Utc::now(),
+            version: first.version,
+            idempotency_key: None,
+            position: 0,
+        };
+        self.inner.append_and_publish_event(event).await
+    }
+}
+
+#[async_trait]
+impl EventStore for ConflictingEventStore {
+    async fn append_and_publish_event(&self, event: Event) -> Result<(), AuctionError> {
+        self.append_and_publish_events(vec![event]).await
+    }
+
+    async fn append_and_publish_events(&self, events: Vec<Event>) -> Result<(), AuctionError> {
+        let remaining = self.remaining.load(Ordering::SeqCst);
+        if remaining > 0 {
+            self.remaining.store(remaining - 1, Ordering::SeqCst);
+            self.append_rival_bid(&events).await?;
+        }
+        let result = self.inner.append_and_publish_events(events).await;
+        if matches!(result, Err(AuctionError::VersionConflict)) {
+            self.conflicts.fetch_add(1, Ordering::SeqCst);
+        }
+        result
+    }
+
+    async fn load_events(
+        &self,
+        aggregate_id: i64,
+        after_version: i64,
+    ) -> Result<Vec<Event>, AuctionError> {
+        self.inner.load_events(aggregate_id, after_version).await
+    }
+
+    async fn load_snapshot(
+        &self,
+        aggregate_id: i64,
+        schema_version: i32,
+    ) -> Result<Option<Snapshot>, AuctionError> {
+        self.inner.load_snapshot(aggregate_id, schema_version).await
+    }
+
+    async fn save_snapshot(&self, snapshot: Snapshot) -> Result<(), AuctionError> {
+        self.inner.save_snapshot(snapshot).await
+    }
+
+    async fn delete_snapshots(&self) -> Result<u64, AuctionError> {
+        self.inner.delete_snapshots().await
+    }
+
+    async fn next_aggregate_id(&self) -> Result<i64, AuctionError> {
+        self.inner.next_aggregate_id().await
+    }
+}
@@ -0,0 +1,81 @@
+use auction_service::bidding::increment::{
+    min_increment, parse_ladder, validate_ladder, IncrementStep,
+};
+
+// 입찰 단위 테스트
+// 입찰 단위 설정 파싱, 구간 검증 및 가격별 최소 증가 금액 계산
+
+/// 입찰 단위 설정 파싱 테스트
+/// 가격 상한 오름차순으로 정렬하며, 상한 없는 구간은 마지막
+#[test]
+fn test_parse_ladder() {
+    let ladder = parse_ladder("100000:1000, *:5000, 10000:500").unwrap();
+    assert_eq!(
+        ladder,
+        vec![
+            step(Some(10_000), 500),
+            step(Some(100_000), 1_000),
+            step(None, 5_000),
+        ]
+    );
+
+    // 상한 없는 구간 없이 지정 가능
+    let ladder = parse_ladder("10000:500").unwrap();
+    assert_eq!(ladder, vec![step(Some(10_000), 500)]);
+}
+
+/// 잘못된 입찰 단위 설정 파싱 테스트
+#[test]
+fn test_parse_ladder_rejects_malformed_input() {
+    for value in [
+        "",
+        "10000",
+        "10000:",
+        "abc:500",
+        "10000:abc",
+        "10000:0",
+        "10000:-500",
+        "0:500",
+        "10000:500,10000:1000",
+        "*:500,*:1000",
+    ] {
+        assert!(parse_ladder(value).is_err(), "{}", value);
+    }
+}
+
+/// 입찰 단위 구간 검증 테스트
+/// 정렬되지 않았거나 비어 있는 구간, 중간의 상한 없는 구간은 거절
+#[test]
+fn test_validate_ladder() {
+    assert!(validate_ladder(&[step(Some(10_000), 500), step(None, 1_000)]).is_ok());
+
+    assert!(validate_ladder(&[]).is_err());
+    assert!(validate_ladder(&[step(Some(100_000), 1_000), step(Some(10_000), 500)]).is_err());
+    assert!(validate_ladder(&[step(None, 1_000), step(Some(10_000), 500)]).is_err());
+    assert!(validate_ladder(&[step(Some(10_000), 0)]).is_err());
+}
+
+/// 최소 증가 금액 테스트
+/// 현재 가격이 상한 미만인 첫 구간 적용 (상한과 같으면 다음 구간)
+#[test]
+fn test_min_increment() {
+    let ladder = parse_ladder("10000:500,100000:1000,*:5000").unwrap();
+    assert_eq!(min_increment(&ladder, 0), 500);
+    assert_eq!(min_increment(&ladder, 9_999), 500);
+    assert_eq!(min_increment(&ladder, 10_000), 1_000);
+    assert_eq!(min_increment(&ladder, 99_999), 1_000);
+    assert_eq!(min_increment(&ladder, 100_000), 5_000);
+    assert_eq!(min_increment(&ladder, i64::MAX), 5_000);
+
+    // 상한 없는 구간이 없으면 마지막 구간 적용
+    let ladder = parse_ladder("10000:500,100000:1000").unwrap();
+    assert_eq!(min_increment(&ladder, 500_000), 1_000);
+
+    // 구간이 없으면 1
+    assert_eq!(min_increment(&[], 10_000), 1);
+}
+
+/// 입찰 단위 구간 생성
+fn step(below: Option<i64>, increment: i64) -> IncrementStep {
+    IncrementStep { below, increment }
+}
@@ -1,12 +1,18 @@
+use auction_service::auction::events::AuctionEvent;
+use auction_service::bidding::increment::IncrementStep;
 use auction_service::bidding::model::Item;
 use auction_service::database::DatabaseManager;
+use auction_service::event_store::{Event, EventStore, PostgresEventStore};
 use auction_service::query;
 use axum::http::StatusCode;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
+use futures_util::{SinkExt, StreamExt};
 use reqwest::Client;
 use serde_json::json;
 use serde_json::Value;
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 use std::sync::Arc;
+use tokio_tungstenite::tungstenite::Message;
 use tracing::{error, info};
 
 /// 트레이싱 초기화
@@ -25,152 +31,1780 @@ async fn setup() -> Arc<DatabaseManager> {
     Arc::new(DatabaseManager::new().await)
 }
 
+/// 관리자 API 토큰 (서비스와 같은 `ADMIN_TOKEN` 환경 변수 사용)
+fn admin_token() -> String {
+    std::env::var("ADMIN_TOKEN").expect("ADMIN_TOKEN must be set")
+}
+
 /// 입찰 테스트
 #[tokio::test]
-async fn test_place_bid() {
+async fn test_place_bid() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성
+    let item = create_test_item(
+        &db_manager,
+        "입찰 테스트 아이템".to_string(),
+        "입찰 기능 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+
+    // 입찰 요청 생성
+    let bid_data = json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 1000
+    });
+
+    // 입찰 처리
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert!(response.status().is_success());
+
+    // 이벤트 처리 대기
+    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+
+    // 데이터베이스에서 업데이트된 아이템 조회
+    let updated_item = query::handlers::get_item(&db_manager, item.id)
+        .await
+        .unwrap();
+    assert_eq!(updated_item.current_price, item.current_price + 1000);
+}
+
+/// 즉시 구매 테스트
+#[tokio::test]
+async fn test_buy_now() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성
+    let item = create_test_item(
+        &db_manager,
+        "즉시 구매 테스트 아이템".to_string(),
+        "즉시 구매 기능 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+
+    // 즉시 구매 요청 생성
+    let buy_now_data = json!({
+        "item_id": item.id,
+        "buyer_id": 2
+    });
+
+    // 즉시 구매 처리
+    let response = client
+        .post("http://localhost:3000/buy-now")
+        .json(&buy_now_data)
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert!(response.status().is_success());
+
+    // 이벤트 처리 대기
+    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+
+    // 데이터베이스에서 업데이트된 아이템 조회
+    let updated_item = query::handlers::get_item(&db_manager, item.id)
+        .await
+        .unwrap();
+    assert_eq!(updated_item.status, "COMPLETED");
+
+    // 시작 가격과 즉시 구매 가격이 같은 상품도 즉시 구매가 조회 모델에 반영됨
+    let start_time = Utc::now() + Duration::seconds(1);
+    let response = client
+        .post("http://localhost:3000/items")
+        .header("X-Seller", "TestSeller")
+        .json(&json!({
+            "title": "즉시 구매 가격 동일 테스트 아이템",
+            "starting_price": 10000,
+            "buy_now_price": 10000,
+            "start_time": start_time,
+            "end_time": start_time + Duration::hours(2)
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body: Value = response.json().await.unwrap();
+    let item_id = body["item_id"].as_i64().unwrap();
+    wait_for_item(&db_manager, item_id, |item| item.status == "ACTIVE").await;
+
+    let response = client
+        .post("http://localhost:3000/buy-now")
+        .json(&json!({"item_id": item_id, "buyer_id": 2}))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+    let item = wait_for_item(&db_manager, item_id, |item| item.status == "COMPLETED").await;
+    assert_eq!(item.current_price, 10000);
+    assert_eq!(item.winner_id, Some(2));
+    let bids = query::handlers::get_bid_history(&db_manager, item_id)
+        .await
+        .unwrap();
+    assert_eq!(bids.len(), 1);
+}
+
+/// 자동 입찰(최대 입찰가) 테스트
+#[tokio::test]
+async fn test_proxy_bid() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성
+    let item = create_test_item(
+        &db_manager,
+        "자동 입찰 테스트 아이템".to_string(),
+        "자동 입찰 기능 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+
+    // 최대 입찰가를 포함한 입찰 요청
+    let proxy_bid_data = json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 1000,
+        "max_bid": item.current_price + 10000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&proxy_bid_data)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert!(response.status().is_success());
+
+    // 경쟁 입찰 요청 (최대 입찰가보다 낮은 금액)
+    let bid_data = json!({
+        "item_id": item.id,
+        "bidder_id": 2,
+        "bid_amount": item.current_price + 3000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert!(response.status().is_success());
+
+    // 이벤트 처리 대기
+    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+
+    // 자동 입찰로 경쟁 입찰가보다 한 단계 높은 가격이 되어야 함
+    let updated_item = query::handlers::get_item(&db_manager, item.id)
+        .await
+        .unwrap();
+    assert_eq!(updated_item.current_price, item.current_price + 4000);
+
+    // 입찰 이력에서 자동 입찰 확인
+    let bid_history = query::handlers::get_bid_history(&db_manager, item.id)
+        .await
+        .unwrap();
+    let top_bid = &bid_history[0];
+    assert_eq!(top_bid.bidder_id, 1);
+    assert!(top_bid.is_auto);
+}
+
+/// 최소 입찰 단위 테스트
+#[tokio::test]
+async fn test_bid_increment_too_small() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성 (상품별 입찰 단위 5,000원)
+    let item = {
+        let mut item = create_test_item(
+            &db_manager,
+            "입찰 단위 테스트 아이템".to_string(),
+            "최소 입찰 단위 검증 테스트를 위한 아이템입니다.".to_string(),
+        )
+        .await;
+        item.bid_increments = Some(sqlx::types::Json(vec![IncrementStep {
+            below: None,
+            increment: 5000,
+        }]));
+        update_test_item(&db_manager, item).await
+    };
+
+    // 첫 입찰은 시작 가격으로 입찰 가능
+    place_test_bid(&client, item.id, 2, item.current_price).await;
+
+    // 입찰 단위보다 작은 금액으로 입찰
+    let bid_data = json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 1000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let error_info: Value = response.json().await.unwrap();
+    assert_eq!(error_info["code"], "BID_INCREMENT_TOO_SMALL");
+    assert_eq!(error_info["status"], 422);
+    assert_eq!(
+        error_info["next_acceptable_amount"],
+        item.current_price + 5000
+    );
+
+    // 다음 입찰 가능 금액으로 입찰
+    let bid_data = json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 5000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert!(response.status().is_success());
+}
+
+/// 마감 직전 입찰 시 경매 연장 테스트
+#[tokio::test]
+async fn test_soft_close_extension() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성 (종료 30초 전, 마감 2분 내 입찰 시 2분 연장)
+    let item = {
+        let mut item = create_test_item(
+            &db_manager,
+            "경매 연장 테스트 아이템".to_string(),
+            "마감 직전 입찰 시 경매 연장 테스트를 위한 아이템입니다.".to_string(),
+        )
+        .await;
+        item.end_time = Utc::now() + Duration::seconds(30);
+        item.soft_close_window_secs = 120;
+        item.soft_close_extension_secs = 120;
+        update_test_item(&db_manager, item).await
+    };
+
+    // 입찰 요청 생성
+    let bid_data = json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 1000
+    });
+
+    // 입찰 처리
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert!(response.status().is_success());
+
+    // 이벤트 처리 대기
+    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+
+    // 종료 시간이 연장 시간만큼 늘어났는지 확인
+    let updated_item = query::handlers::get_item(&db_manager, item.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        updated_item.end_time.timestamp(),
+        (item.end_time + Duration::seconds(120)).timestamp()
+    );
+}
+
+/// 경매 사이클 테스트
+#[tokio::test]
+async fn test_auction_lifecycle() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성 (시작 시간을 현재 시간으로 설정)
+    let item_id = {
+        let mut item = create_test_item(
+            &db_manager,
+            "경매 사이클 테스트 아이템".to_string(),
+            "경매 사이클 테스트(입찰 및 종료 대기, 종료 후 상태 확인)를 위한 아이템입니다."
+                .to_string(),
+        )
+        .await;
+        item.start_time = Utc::now();
+        item.end_time = Utc::now() + Duration::seconds(5);
+        update_test_item(&db_manager, item).await.id
+    };
+
+    // 경매 시작 전 상태 확인
+    let initial_item = query::handlers::get_item(&db_manager, item_id)
+        .await
+        .unwrap();
+    assert_eq!(initial_item.status, "ACTIVE");
+
+    // 입찰 요청 생성
+    let bid_data = json!({
+        "item_id": item_id,
+        "bidder_id": 1,
+        "bid_amount": initial_item.current_price + 5000
+    });
+
+    // 입찰 처리
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert!(response.status().is_success());
+
+    // 이벤트 처리 대기
+    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+
+    // 현재 상태 확인
+    let current_item = query::handlers::get_item(&db_manager, item_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        current_item.current_price,
+        initial_item.current_price + 5000
+    );
+
+    // 경매 종료 대기
+    tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+
+    // 경매 종료 후 상태 및 낙찰자 확인
+    let final_item = query::handlers::get_item(&db_manager, item_id)
+        .await
+        .unwrap();
+    assert_eq!(final_item.status, "COMPLETED");
+    assert_eq!(final_item.winner_id, Some(1));
+
+    // 경매 상태 조회에서 낙찰자 확인
+    let auction_state: Value = client
+        .get(format!("http://localhost:3000/auction/{}", item_id))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(auction_state["winner_id"], 1);
+}
+
+/// 최저 낙찰가 미달 테스트
+#[tokio::test]
+async fn test_reserve_not_met() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성 (최저 낙찰가 100,000원, 5초 후 종료)
+    let item = {
+        let mut item = create_test_item(
+            &db_manager,
+            "최저 낙찰가 테스트 아이템".to_string(),
+            "최저 낙찰가 미달 시 경매 종료 상태 테스트를 위한 아이템입니다.".to_string(),
+        )
+        .await;
+        item.end_time = Utc::now() + Duration::seconds(5);
+        item.reserve_price = Some(100000);
+        update_test_item(&db_manager, item).await
+    };
+
+    // 최저 낙찰가보다 낮은 입찰
+    let bid_data = json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 1000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert!(response.status().is_success());
+
+    // 이벤트 처리 대기
+    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+
+    // 최저 낙찰가 금액은 노출하지 않고 충족 여부만 노출
+    let auction_state: Value = client
+        .get(format!("http://localhost:3000/auction/{}", item.id))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(auction_state["reserve_met"], false);
+    assert!(auction_state.get("reserve_price").is_none());
+
+    // 경매 종료 대기
+    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+    // 최저 낙찰가 미달로 종료
+    let final_item = query::handlers::get_item(&db_manager, item.id)
+        .await
+        .unwrap();
+    assert_eq!(final_item.status, "UNSOLD_RESERVE_NOT_MET");
+}
+
+/// 경매 시작 이벤트 테스트
+#[tokio::test]
+async fn test_auction_started_event() {
+    let db_manager = setup().await;
+
+    // 테스트용 아이템 생성 (2초 후 시작)
+    let item = {
+        let mut item = create_test_item(
+            &db_manager,
+            "경매 시작 테스트 아이템".to_string(),
+            "경매 시작 이벤트 테스트를 위한 아이템입니다.".to_string(),
+        )
+        .await;
+        item.start_time = Utc::now() + Duration::seconds(2);
+        item.status = "SCHEDULED".to_string();
+        update_test_item(&db_manager, item).await
+    };
+
+    // 경매 시작 대기
+    tokio::time::sleep(tokio::time::Duration::from_millis(3500)).await;
+
+    // 경매 상태 확인
+    let started_item = query::handlers::get_item(&db_manager, item.id)
+        .await
+        .unwrap();
+    assert_eq!(started_item.status, "ACTIVE");
+
+    // 이벤트 저장소에 경매 시작 이벤트가 기록되었는지 확인
+    let event_types = db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query_scalar::<_, String>(
+                    "SELECT event_type FROM events WHERE aggregate_id = $1 ORDER BY version",
+                )
+                .bind(item.id)
+                .fetch_all(&mut **tx)
+                .await
+            })
+        })
+        .await
+        .unwrap();
+    assert_eq!(event_types, vec!["AuctionStarted".to_string()]);
+}
+
+/// 스냅샷 재생성 테스트
+#[tokio::test]
+async fn test_rebuild_snapshots() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성
+    let item = create_test_item(
+        &db_manager,
+        "스냅샷 테스트 아이템".to_string(),
+        "스냅샷 재생성 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+
+    // 관리자 토큰이 없거나 일치하지 않으면 거절
+    let response = client
+        .post("http://localhost:3000/admin/snapshots/rebuild")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["code"], "ADMIN_REQUIRED");
+
+    let response = client
+        .post("http://localhost:3000/admin/snapshots/rebuild")
+        .header("X-Admin-Token", "invalid-token")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // 스냅샷 재생성 요청
+    let response = client
+        .post("http://localhost:3000/admin/snapshots/rebuild")
+        .header("X-Admin-Token", admin_token())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = response.json().await.unwrap();
+    assert!(body["rebuilt"].is_u64());
+
+    // 재생성 후에도 애그리거트 기준 입찰이 정상 처리되는지 확인
+    let bid_data = serde_json::json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 1000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// 아웃박스 발행 테스트
+#[tokio::test]
+async fn test_outbox_relay() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성
+    let item = create_test_item(
+        &db_manager,
+        "아웃박스 테스트 아이템".to_string(),
+        "아웃박스 발행 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+
+    // 입찰
+    let bid_data = serde_json::json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 1000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // 릴레이 발행 및 이벤트 처리 대기
+    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+
+    // 입찰 이벤트가 outbox에 기록되고 발행 완료로 표시되었는지 확인
+    let (total, pending) = db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query_as::<_, (i64, i64)>(
+                    "SELECT COUNT(*), COUNT(*) FILTER (WHERE o.sent_at IS NULL)
+                     FROM outbox o JOIN events e ON e.id = o.event_id
+                     WHERE e.aggregate_id = $1",
+                )
+                .bind(item.id)
+                .fetch_one(&mut **tx)
+                .await
+            })
+        })
+        .await
+        .unwrap();
+    assert!(total > 0);
+    assert_eq!(pending, 0);
+
+    // 조회 모델 반영 확인
+    let updated_item = query::handlers::get_item(&db_manager, item.id)
+        .await
+        .unwrap();
+    assert_eq!(updated_item.current_price, item.current_price + 1000);
+
+    // 재시도 대기 중인 메시지가 있으면 같은 애그리거트의 뒤 메시지는 발행하지 않음 (같은 이벤트를 다시 기록하여 재전달)
+    let (blocked_id, later_id) = db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                let mut ids = Vec::new();
+                for delay_secs in [3600, 0] {
+                    let id = sqlx::query_scalar::<_, i64>(
+                        "INSERT INTO outbox (event_id, topic, message_key, payload, next_attempt_at)
+                         SELECT o.event_id, o.topic, o.message_key, o.payload, NOW() + make_interval(secs => $2)
+                         FROM outbox o JOIN events e ON e.id = o.event_id
+                         WHERE e.aggregate_id = $1
+                         ORDER BY o.id DESC
+                         LIMIT 1
+                         RETURNING id",
+                    )
+                    .bind(item.id)
+                    .bind(delay_secs as f64)
+                    .fetch_one(&mut **tx)
+                    .await?;
+                    ids.push(id);
+                }
+                Ok::<_, sqlx::Error>((ids[0], ids[1]))
+            })
+        })
+        .await
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+    let sent = outbox_sent_at(&db_manager, later_id).await;
+    assert!(sent.is_none());
+
+    // 앞선 메시지가 발행되면 뒤의 메시지도 순서대로 발행
+    db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query("UPDATE outbox SET next_attempt_at = NOW() WHERE id = $1")
+                    .bind(blocked_id)
+                    .execute(&mut **tx)
+                    .await
+            })
+        })
+        .await
+        .unwrap();
+    tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+    let blocked_sent = outbox_sent_at(&db_manager, blocked_id).await.unwrap();
+    let later_sent = outbox_sent_at(&db_manager, later_id).await.unwrap();
+    assert!(blocked_sent <= later_sent);
+}
+
+/// 멱등성 키 테스트
+#[tokio::test]
+async fn test_idempotent_bid() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성
+    let item = create_test_item(
+        &db_manager,
+        "멱등성 테스트 아이템".to_string(),
+        "멱등성 키 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+
+    let idempotency_key = format!("test-idempotent-bid-{}", item.id);
+    let bid_data = serde_json::json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 1000
+    });
+
+    // 첫 번째 요청
+    let first = client
+        .post("http://localhost:3000/bid")
+        .header("Idempotency-Key", &idempotency_key)
+        .json(&bid_data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(first.status(), StatusCode::OK);
+    let first_body: Value = first.json().await.unwrap();
+
+    // 같은 키로 재요청 시 저장된 응답 반환
+    let second = client
+        .post("http://localhost:3000/bid")
+        .header("Idempotency-Key", &idempotency_key)
+        .json(&bid_data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(second.status(), StatusCode::OK);
+    assert_eq!(second.headers()["Idempotent-Replayed"], "true");
+    let second_body: Value = second.json().await.unwrap();
+    assert_eq!(first_body, second_body);
+
+    // 같은 키로 다른 요청 시 오류
+    let other_bid_data = serde_json::json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 2000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .header("Idempotency-Key", &idempotency_key)
+        .json(&other_bid_data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    // 입찰 이벤트는 한 번만 기록되고 멱등성 키가 함께 저장되었는지 확인
+    let event_count = db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query_scalar::<_, i64>(
+                    "SELECT COUNT(*) FROM events WHERE aggregate_id = $1 AND idempotency_key = $2",
+                )
+                .bind(item.id)
+                .bind(&idempotency_key)
+                .fetch_one(&mut **tx)
+                .await
+            })
+        })
+        .await
+        .unwrap();
+    assert_eq!(event_count, 1);
+}
+
+/// 멱등성 키 해제 및 재선점 테스트
+/// 오류 응답은 저장하지 않고 키를 해제하며, 응답 없이 임대 시간이 지난 키는 새 요청이 다시 선점
+#[tokio::test]
+async fn test_idempotency_key_recovery() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    let item = create_test_item(
+        &db_manager,
+        "멱등성 키 재선점 테스트 아이템".to_string(),
+        "멱등성 키 해제 및 재선점 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+
+    // 오류 응답 후 같은 키로 다시 요청하면 명령 실행
+    let idempotency_key = format!("test-idempotency-release-{}", item.id);
+    let bid_data = json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 1000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .header("Idempotency-Key", &idempotency_key)
+        .json(&json!({
+            "item_id": item.id,
+            "bidder_id": 1,
+            "bid_amount": item.current_price - 1
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let response = client
+        .post("http://localhost:3000/bid")
+        .header("Idempotency-Key", &idempotency_key)
+        .json(&bid_data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // 처리 중 중단되어 응답이 저장되지 않은 키 (임대 시간 경과)
+    let stale_key = format!("test-idempotency-stale-{}", item.id);
+    let key = stale_key.clone();
+    db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query(
+                    "INSERT INTO idempotency_keys (idempotency_key, endpoint, request, created_at, expires_at, locked_at)
+                    VALUES ($1, '/bid', '{}', NOW() - INTERVAL '1 minute', NOW() + INTERVAL '1 hour', NOW() - INTERVAL '1 minute')",
+                )
+                .bind(&key)
+                .execute(&mut **tx)
+                .await
+            })
+        })
+        .await
+        .unwrap();
+    let response = client
+        .post("http://localhost:3000/bid")
+        .header("Idempotency-Key", &stale_key)
+        .json(&json!({
+            "item_id": item.id,
+            "bidder_id": 2,
+            "bid_amount": item.current_price + 2000
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("Idempotent-Replayed").is_none());
+}
+
+/// 이벤트 재전달 테스트
+#[tokio::test]
+async fn test_redelivered_event_is_not_projected_twice() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성
+    let item = create_test_item(
+        &db_manager,
+        "재전달 테스트 아이템".to_string(),
+        "이벤트 재전달 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+
+    // 입찰
+    let bid_data = serde_json::json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 1000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // 이벤트 처리 대기
+    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+
+    // 같은 이벤트를 outbox로 다시 발행 (재전달 상황)
+    let event = db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query_as::<_, Event>(
+                    "SELECT id, aggregate_id, event_type, data, timestamp, version, idempotency_key
+                     FROM events WHERE aggregate_id = $1 AND event_type = 'BidPlaced'",
+                )
+                .bind(item.id)
+                .fetch_one(&mut **tx)
+                .await
+            })
+        })
+        .await
+        .unwrap();
+    publish_via_outbox(
+        &db_manager,
+        event.id,
+        event.aggregate_id.to_string(),
+        serde_json::to_value(&event).unwrap(),
+    )
+    .await;
+
+    // 이벤트 처리 대기
+    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+
+    // 입찰 내역이 한 번만 반영되었는지 확인
+    let bids = query::handlers::get_item_bids(&db_manager, item.id)
+        .await
+        .unwrap();
+    assert_eq!(bids.len(), 1);
+}
+
+/// 이벤트 버전 누락 테스트
+#[tokio::test]
+async fn test_projection_fills_version_gap() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성
+    let item = create_test_item(
+        &db_manager,
+        "버전 누락 테스트 아이템".to_string(),
+        "이벤트 버전 누락 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+
+    // 발행되지 않은 입찰 이벤트(version 1)를 이벤트 저장소에 직접 기록
+    let first_bid_amount = item.current_price + 1000;
+    let first_bid = json!({
+        "BidPlaced": {
+            "item_id": item.id,
+            "bidder_id": 1,
+            "bid_amount": first_bid_amount,
+            "timestamp": Utc::now()
+        }
+    });
+    db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query(
+                    "INSERT INTO events (aggregate_id, event_type, data, timestamp, version)
+                     VALUES ($1, 'BidPlaced', $2, $3, 1)",
+                )
+                .bind(item.id)
+                .bind(first_bid)
+                .bind(Utc::now())
+                .execute(&mut **tx)
+                .await
+            })
+        })
+        .await
+        .unwrap();
+
+    // 입찰 (version 2, Kafka로 발행)
+    let bid_data = serde_json::json!({
+        "item_id": item.id,
+        "bidder_id": 2,
+        "bid_amount": first_bid_amount + 1000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // 이벤트 처리 대기
+    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+
+    // 누락된 version 1 이벤트도 조회 모델에 반영되었는지 확인
+    let bids = query::handlers::get_item_bids(&db_manager, item.id)
+        .await
+        .unwrap();
+    assert_eq!(bids.len(), 2);
+    let updated_item = query::handlers::get_item(&db_manager, item.id)
+        .await
+        .unwrap();
+    assert_eq!(updated_item.current_price, first_bid_amount + 1000);
+}
+
+/// 데드레터 테스트
+#[tokio::test]
+async fn test_dead_letter() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    // 테스트용 아이템 생성 및 입찰
+    let item = create_test_item(
+        &db_manager,
+        "데드레터 테스트 아이템".to_string(),
+        "데드레터 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+    let bid_data = serde_json::json!({
+        "item_id": item.id,
+        "bidder_id": 1,
+        "bid_amount": item.current_price + 1000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let event_id = db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query_scalar::<_, i64>("SELECT id FROM events WHERE aggregate_id = $1")
+                    .bind(item.id)
+                    .fetch_one(&mut **tx)
+                    .await
+            })
+        })
+        .await
+        .unwrap();
+
+    // 역직렬화할 수 없는 메시지 발행
+    let payload = json!(format!("invalid-event-{}", item.id));
+    publish_via_outbox(
+        &db_manager,
+        event_id,
+        "dead-letter-test".to_string(),
+        payload.clone(),
+    )
+    .await;
+
+    // 이벤트 처리 대기
+    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+
+    // 데드레터 목록에 기록되었는지 확인 (outbox의 JSON 페이로드가 그대로 발행됨)
+    let expected_payload = payload.to_string();
+    let response = client
+        .get("http://localhost:3000/admin/dead-letters")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let dead_letters: Vec<Value> = client
+        .get("http://localhost:3000/admin/dead-letters")
+        .header("X-Admin-Token", admin_token())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let dead_letter = dead_letters
+        .iter()
+        .find(|dead_letter| dead_letter["payload"].as_str() == Some(expected_payload.as_str()))
+        .expect("데드레터가 기록되지 않음");
+    assert_eq!(dead_letter["topic"], "events");
+    assert!(dead_letter["error"]
+        .as_str()
+        .unwrap()
+        .contains("deserialize"));
+
+    // 원인이 해결되지 않은 데드레터는 재처리 실패
+    let response = client
+        .post(format!(
+            "http://localhost:3000/admin/dead-letters/{}/replay",
+            dead_letter["id"]
+        ))
+        .header("X-Admin-Token", admin_token())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+/// 오류 응답 형식 테스트
+/// 오류는 RFC 7807 Problem Details 형식(`application/problem+json`)으로 응답
+#[tokio::test]
+async fn test_problem_details() {
+    let client = Client::new();
+    let missing_item_id = i64::MAX;
+
+    // 존재하지 않는 상품 조회
+    let response = client
+        .get(format!("http://localhost:3000/items/{}", missing_item_id))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/problem+json"
+    );
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["type"], "/problems/not-found");
+    assert_eq!(problem["status"], 404);
+    assert_eq!(problem["code"], "NOT_FOUND");
+    assert!(problem["title"].is_string());
+    assert!(problem["detail"].is_string());
+
+    // 존재하지 않는 상품 입찰
+    let bid_data = json!({
+        "item_id": missing_item_id,
+        "bidder_id": 1,
+        "bid_amount": 10000
+    });
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&bid_data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["code"], "NOT_FOUND");
+}
+
+/// 오류 메시지 다국어 테스트
+/// `Accept-Language` 헤더로 응답 언어를 결정하고, 지원하지 않는 언어는 한국어로 응답
+#[tokio::test]
+async fn test_localized_problem_details() {
+    let client = Client::new();
+    let url = format!("http://localhost:3000/items/{}", i64::MAX);
+
+    // 영어 우선
+    let response = client
+        .get(&url)
+        .header("Accept-Language", "fr-FR, en-US;q=0.9, ko;q=0.8")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()["content-language"], "en");
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["code"], "NOT_FOUND");
+    assert_eq!(problem["title"], "Resource not found.");
+    assert_eq!(
+        problem["detail"],
+        format!("Item not found: id={}", i64::MAX)
+    );
+
+    // 한국어 우선
+    let response = client
+        .get(&url)
+        .header("Accept-Language", "en;q=0.5, ko-KR")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.headers()["content-language"], "ko");
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["code"], "NOT_FOUND");
+    assert_eq!(problem["title"], "리소스를 찾을 수 없습니다.");
+
+    // 지원하지 않는 언어는 한국어
+    let response = client
+        .get(&url)
+        .header("Accept-Language", "fr")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.headers()["content-language"], "ko");
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["title"], "리소스를 찾을 수 없습니다.");
+}
+
+/// 실시간 구독 테스트
+/// 연결 시 현재 상태를 받고, 반영된 입찰 이벤트를 실시간으로 수신하며, 재연결 시 마지막 버전 이후 이벤트부터 수신
+#[tokio::test]
+async fn test_realtime_subscription() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    let item = create_test_item(
+        &db_manager,
+        "실시간 구독 테스트 아이템".to_string(),
+        "실시간 구독 기능 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+    let url = format!("ws://localhost:3000/ws/auction/{}", item.id);
+
+    // 연결 시 현재 상품 상태 수신
+    let (mut socket, _) = tokio_tungstenite::connect_async(&url).await.unwrap();
+    let snapshot = next_message(&mut socket).await;
+    assert_eq!(snapshot["type"], "snapshot");
+    assert_eq!(snapshot["version"], 0);
+    assert_eq!(snapshot["item"]["id"], item.id);
+
+    // 입찰 이벤트 실시간 수신
+    let first_bid = item.current_price + 1000;
+    place_test_bid(&client, item.id, 1, first_bid).await;
+    let event = next_message(&mut socket).await;
+    assert_eq!(event["type"], "event");
+    assert_eq!(event["event_type"], "BidPlaced");
+    assert_eq!(event["version"], 1);
+    assert_eq!(event["data"]["bid_amount"], first_bid);
+    socket.close(None).await.unwrap();
+
+    // 연결이 끊긴 동안 입찰
+    let second_bid = first_bid + 1000;
+    place_test_bid(&client, item.id, 2, second_bid).await;
+
+    // 마지막으로 받은 버전으로 재연결하면 누락된 이벤트부터 수신
+    let (mut socket, _) = tokio_tungstenite::connect_async(format!("{}?last_version=1", url))
+        .await
+        .unwrap();
+    let event = next_message(&mut socket).await;
+    assert_eq!(event["type"], "event");
+    assert_eq!(event["version"], 2);
+    assert_eq!(event["data"]["bid_amount"], second_bid);
+
+    // 메시지로 이어받을 버전 지정
+    socket
+        .send(Message::Text(json!({"last_version": 0}).to_string()))
+        .await
+        .unwrap();
+    assert_eq!(next_message(&mut socket).await["version"], 1);
+    assert_eq!(next_message(&mut socket).await["version"], 2);
+
+    // 존재하지 않는 상품은 연결 거절
+    assert!(tokio_tungstenite::connect_async(format!(
+        "ws://localhost:3000/ws/auction/{}",
+        i64::MAX
+    ))
+    .await
+    .is_err());
+}
+
+/// SSE 구독 테스트
+/// 반영된 이벤트를 SSE로 수신하고, `Last-Event-ID`로 재연결 시 해당 이벤트 위치 이후부터 수신
+#[tokio::test]
+async fn test_sse_stream() {
+    let db_manager = setup().await;
+    let client = Client::new();
+
+    let item = create_test_item(
+        &db_manager,
+        "SSE 구독 테스트 아이템".to_string(),
+        "SSE 구독 기능 테스트를 위한 아이템입니다.".to_string(),
+    )
+    .await;
+    let url = format!("http://localhost:3000/auction/{}/stream", item.id);
+
+    // 구독 후 입찰 이벤트 수신
+    let response = client.get(&url).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .starts_with("text/event-stream"));
+    let mut stream = SseReader::new(response);
+    let first_bid = item.current_price + 1000;
+    place_test_bid(&client, item.id, 1, first_bid).await;
+    let (first_id, event_type, data) = stream.next_event().await;
+    assert_eq!(event_type, "BidPlaced");
+    assert_eq!(data["version"], 1);
+    assert_eq!(data["data"]["bid_amount"], first_bid);
+    drop(stream);
+
+    // SSE 이벤트 id는 이벤트 저장소의 이벤트 위치
+    let events = query::handlers::get_events_after_position(&db_manager, 0, Some(item.id), 10)
+        .await
+        .unwrap();
+    let bid_event = events.iter().find(|event| event.version == 1).unwrap();
+    assert_eq!(bid_event.position, first_id);
+
+    // 연결이 끊긴 동안 입찰
+    let second_bid = first_bid + 1000;
+    place_test_bid(&client, item.id, 2, second_bid).await;
+
+    // Last-Event-ID 이후 이벤트부터 수신
+    let response = client
+        .get(&url)
+        .header("Last-Event-ID", first_id.to_string())
+        .send()
+        .await
+        .unwrap();
+    let mut stream = SseReader::new(response);
+    let (second_id, _, data) = stream.next_event().await;
+    assert!(second_id > first_id);
+    assert_eq!(data["version"], 2);
+    assert_eq!(data["data"]["bid_amount"], second_bid);
+
+    // 전체 상품 구독에서도 같은 위치로 이어받기
+    let response = client
+        .get("http://localhost:3000/items/stream")
+        .header("Last-Event-ID", (first_id - 1).to_string())
+        .send()
+        .await
+        .unwrap();
+    let mut stream = SseReader::new(response);
+    loop {
+        let (id, _, data) = stream.next_event().await;
+        if data["item_id"] == item.id {
+            assert_eq!(id, first_id);
+            break;
+        }
+    }
+
+    // 존재하지 않는 상품 구독
+    let response = client
+        .get(format!("http://localhost:3000/auction/{}/stream", i64::MAX))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+/// 이벤트 저장 동시성 테스트
+/// 다른 상품의 저장 트랜잭션이 진행 중이어도 이벤트 저장이 대기하지 않으며, 먼저 위치를 발급받은 이벤트가 나중에 커밋되어도 위치 이후 이벤트 조회에서 누락되지 않음
+#[tokio::test]
+async fn test_concurrent_appends_across_items() {
+    let db_manager = setup().await;
+    let event_store = Arc::new(PostgresEventStore::new(db_manager.clone()));
+    let items = create_multiple_test_items(&db_manager, 2).await;
+    let (first, second) = (items[0].id, items[1].id);
+    let last_position = query::handlers::get_last_event_position(&db_manager)
+        .await
+        .unwrap();
+
+    // 첫 번째 상품의 version 1을 기록한 트랜잭션을 열어 두어, 같은 버전을 저장하는 명령이 대기하도록 함
+    let mut blocker = db_manager.pool().begin().await.unwrap();
+    sqlx::query(
+        "INSERT INTO events (aggregate_id, event_type, data, timestamp, version)
+         VALUES ($1, 'BidPlaced', $2, $3, 1)",
+    )
+    .bind(first)
+    .bind(test_bid_event(first, 11000).data)
+    .bind(Utc::now())
+    .execute(&mut *blocker)
+    .await
+    .unwrap();
+    let pending = tokio::spawn({
+        let event_store = event_store.clone();
+        async move {
+            event_store
+                .append_and_publish_event(test_bid_event(first, 11000))
+                .await
+        }
+    });
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+    assert!(!pending.is_finished());
+
+    // 다른 상품의 이벤트 저장은 대기하지 않음
+    tokio::time::timeout(
+        tokio::time::Duration::from_secs(5),
+        event_store.append_and_publish_event(test_bid_event(second, 11000)),
+    )
+    .await
+    .expect("다른 상품의 저장 트랜잭션을 기다림")
+    .unwrap();
+
+    // 진행 중인 트랜잭션보다 나중에 시작한 트랜잭션의 이벤트는 아직 조회하지 않음
+    let events = query::handlers::get_events_after_position(&db_manager, last_position, None, 500)
+        .await
+        .unwrap();
+    assert!(!events.iter().any(|event| event.aggregate_id == second));
+    let cursor = events.last().map_or(last_position, |event| event.position);
+
+    // 대기하던 저장 완료 후 조회한 위치 이후로 두 이벤트 모두 조회
+    // 첫 번째 상품의 이벤트는 위치가 더 작지만 나중에 커밋되어도 누락되지 않음
+    blocker.rollback().await.unwrap();
+    tokio::time::timeout(tokio::time::Duration::from_secs(5), pending)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    let events = query::handlers::get_events_after_position(&db_manager, cursor, None, 500)
+        .await
+        .unwrap();
+    let first_event = events.iter().find(|e| e.aggregate_id == first).unwrap();
+    let second_event = events.iter().find(|e| e.aggregate_id == second).unwrap();
+    assert!(first_event.position < second_event.position);
+}
+
+/// 상품 관리 테스트
+/// 등록, 수정, 철회 명령이 이벤트로 기록되고 조회 모델에 반영되며, 등록한 상품은 시작 시간에 경매가 시작됨
+#[tokio::test]
+async fn test_item_management() {
+    let db_manager = setup().await;
+    let client = Client::new();
+    let start_time = Utc::now() + Duration::seconds(2);
+    let listing = json!({
+        "title": "상품 등록 테스트 아이템",
+        "description": "상품 관리 명령 테스트를 위한 아이템입니다.",
+        "starting_price": 10000,
+        "buy_now_price": 500000,
+        "start_time": start_time,
+        "end_time": start_time + Duration::hours(2)
+    });
+
+    // 가격 관계가 맞지 않으면 거절
+    let mut invalid = listing.clone();
+    invalid["buy_now_price"] = json!(5000);
+    let response = client
+        .post("http://localhost:3000/items")
+        .header("X-Seller", "TestSeller")
+        .json(&invalid)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["code"], "INVALID_ITEM");
+    assert_eq!(problem["reason"], "item.buy_now_below_starting_price");
+
+    // 판매자 헤더가 없으면 거절
+    let response = client
+        .post("http://localhost:3000/items")
+        .json(&listing)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["reason"], "item.seller_required");
+
+    // 상품 등록
+    let response = client
+        .post("http://localhost:3000/items")
+        .header("X-Seller", "TestSeller")
+        .json(&listing)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body: Value = response.json().await.unwrap();
+    let item_id = body["item_id"].as_i64().unwrap();
+    let item = wait_for_item(&db_manager, item_id, |item| item.status == "SCHEDULED").await;
+    assert_eq!(item.title, "상품 등록 테스트 아이템");
+    assert_eq!(item.current_price, 10000);
+
+    // 판매자가 아니면 수정 불가
+    let response = client
+        .patch(format!("http://localhost:3000/items/{}", item_id))
+        .header("X-Seller", "OtherSeller")
+        .json(&json!({"starting_price": 20000}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // 경매 시작 전 수정 (같은 멱등성 키로 재요청하면 저장된 응답 반환)
+    let idempotency_key = format!("update-{}", item_id);
+    for _ in 0..2 {
+        let response = client
+            .patch(format!("http://localhost:3000/items/{}", item_id))
+            .header("X-Seller", "TestSeller")
+            .header("Idempotency-Key", &idempotency_key)
+            .json(&json!({"starting_price": 20000}))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    let item = wait_for_item(&db_manager, item_id, |item| item.starting_price == 20000).await;
+    assert_eq!(item.current_price, 20000);
+
+    // 시작 시간이 되면 경매 시작 후 입찰 가능, 시작된 경매는 수정 및 철회 불가
+    wait_for_item(&db_manager, item_id, |item| item.status == "ACTIVE").await;
+    place_test_bid(&client, item_id, 1, 21000).await;
+    let response = client
+        .delete(format!("http://localhost:3000/items/{}", item_id))
+        .header("X-Seller", "TestSeller")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let event_types: Vec<String> =
+        query::handlers::get_events_after_position(&db_manager, 0, Some(item_id), 10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|event| event.event_type)
+            .collect();
+    assert_eq!(
+        event_types[..3],
+        ["ItemListed", "ItemUpdated", "AuctionStarted"]
+    );
+
+    // 다른 상품 등록 후 철회
+    let response = client
+        .post("http://localhost:3000/items")
+        .header("X-Seller", "TestSeller")
+        .json(&listing)
+        .send()
+        .await
+        .unwrap();
+    let body: Value = response.json().await.unwrap();
+    let item_id = body["item_id"].as_i64().unwrap();
+    let response = client
+        .delete(format!("http://localhost:3000/items/{}", item_id))
+        .header("X-Seller", "OtherSeller")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let response = client
+        .delete(format!("http://localhost:3000/items/{}", item_id))
+        .header("X-Seller", "TestSeller")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    wait_for_item(&db_manager, item_id, |item| item.status == "WITHDRAWN").await;
+}
+
+/// 경매 취소 테스트
+/// 진행 중인 경매를 사유와 함께 취소하면 취소 이벤트에 입찰자 id가 기록되고, 이후 입찰은 거절
+#[tokio::test]
+async fn test_auction_cancellation() {
     let db_manager = setup().await;
     let client = Client::new();
 
-    // 테스트용 아이템 생성
     let item = create_test_item(
         &db_manager,
-        "입찰 테스트 아이템".to_string(),
-        "입찰 기능 테스트를 위한 아이템입니다.".to_string(),
+        "경매 취소 테스트 아이템".to_string(),
+        "경매 취소 기능 테스트를 위한 아이템입니다.".to_string(),
     )
     .await;
+    place_test_bid(&client, item.id, 1, 11000).await;
+    place_test_bid(&client, item.id, 2, 12000).await;
+    let url = format!("http://localhost:3000/auction/{}/cancel", item.id);
 
-    // 입찰 요청 생성
-    let bid_data = json!({
-        "item_id": item.id,
-        "bidder_id": 1,
-        "bid_amount": item.current_price + 1000
-    });
+    // 사유 없이 취소 불가
+    let response = client
+        .post(&url)
+        .json(&json!({"reason": ""}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["code"], "CANCEL_REASON_REQUIRED");
 
-    // 입찰 처리
+    // 판매자가 아니면 취소 불가
     let response = client
-        .post("http://localhost:3000/bid")
-        .json(&bid_data)
+        .post(&url)
+        .header("X-Seller", "OtherSeller")
+        .json(&json!({"reason": "판매자 상품 분실"}))
         .send()
         .await
-        .expect("Failed to send request");
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["code"], "NOT_ITEM_SELLER");
 
-    assert!(response.status().is_success());
+    let response = client
+        .post(&url)
+        .header("X-Seller", "TestSeller")
+        .json(&json!({"reason": "판매자 상품 분실"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    wait_for_item(&db_manager, item.id, |item| item.status == "CANCELLED").await;
 
-    // 이벤트 처리 대기
-    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+    let events = query::handlers::get_events_after_position(&db_manager, 0, Some(item.id), 10)
+        .await
+        .unwrap();
+    let cancelled = events.last().unwrap();
+    assert_eq!(cancelled.event_type, "AuctionCancelled");
+    assert_eq!(
+        cancelled.data["AuctionCancelled"]["bidder_ids"],
+        json!([1, 2])
+    );
 
-    // 데이터베이스에서 업데이트된 아이템 조회
-    let updated_item = query::handlers::get_item(&db_manager, item.id)
+    // 취소된 경매 입찰 및 재취소 거절
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&json!({"item_id": item.id, "bidder_id": 3, "bid_amount": 20000}))
+        .send()
         .await
         .unwrap();
-    assert_eq!(updated_item.current_price, item.current_price + 1000);
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["code"], "INVALID_STATUS");
+
+    let response = client
+        .post(&url)
+        .header("X-Seller", "TestSeller")
+        .json(&json!({"reason": "중복 취소"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
 }
 
-/// 즉시 구매 테스트
+/// 입찰 철회 테스트
 #[tokio::test]
-async fn test_buy_now() {
+async fn test_bid_retraction() {
     let db_manager = setup().await;
     let client = Client::new();
 
-    // 테스트용 아이템 생성
     let item = create_test_item(
         &db_manager,
-        "즉시 구매 테스트 아이템".to_string(),
-        "즉시 구매 기능 테스트를 위한 아이템입니다.".to_string(),
+        "입찰 철회 테스트 아이템".to_string(),
+        "입찰 철회 기능 테스트를 위한 아이템입니다.".to_string(),
     )
     .await;
+    place_test_bid(&client, item.id, 1, 11000).await;
+    place_test_bid(&client, item.id, 2, 12000).await;
+    place_test_bid(&client, item.id, 1, 15000).await;
+    wait_for_item(&db_manager, item.id, |item| item.current_price == 15000).await;
 
-    // 즉시 구매 요청 생성
-    let buy_now_data = json!({
-        "item_id": item.id,
-        "buyer_id": 2
-    });
-
-    // 즉시 구매 처리
     let response = client
-        .post("http://localhost:3000/buy-now")
-        .json(&buy_now_data)
+        .post("http://localhost:3000/bid/retract")
+        .json(&json!({"item_id": item.id, "bidder_id": 1}))
         .send()
         .await
-        .expect("Failed to send request");
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["current_price"], 12000);
 
-    assert!(response.status().is_success());
+    // 남은 유효 입찰 기준으로 현재 가격 재계산
+    wait_for_item(&db_manager, item.id, |item| item.current_price == 12000).await;
 
-    // 이벤트 처리 대기
-    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+    let highest_bid: Value = client
+        .get(format!(
+            "http://localhost:3000/auction/{}/highest-bid",
+            item.id
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(highest_bid, 12000);
 
-    // 데이터베이스에서 업데이트된 아이템 조회
-    let updated_item = query::handlers::get_item(&db_manager, item.id)
+    // 철회된 입찰도 이력에 철회 시각과 함께 남음
+    let history: Vec<Value> = client
+        .get(format!("http://localhost:3000/auction/{}/bids", item.id))
+        .send()
+        .await
+        .unwrap()
+        .json()
         .await
         .unwrap();
-    assert_eq!(updated_item.status, "COMPLETED");
+    assert_eq!(history.len(), 3);
+    let retracted: Vec<&Value> = history
+        .iter()
+        .filter(|bid| !bid["retracted_at"].is_null())
+        .collect();
+    assert_eq!(retracted.len(), 1);
+    assert_eq!(retracted[0]["bid_amount"], 15000);
+
+    // 입찰하지 않은 입찰자
+    let response = client
+        .post("http://localhost:3000/bid/retract")
+        .json(&json!({"item_id": item.id, "bidder_id": 3}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["code"], "RETRACTION_NOT_ALLOWED");
+    assert_eq!(problem["reason"], "retraction.no_bid");
+
+    // 경매 종료 1시간 전부터는 철회 불가
+    let mut closing_item = item.clone();
+    closing_item.end_time = Utc::now() + Duration::minutes(30);
+    update_test_item(&db_manager, closing_item).await;
+    let response = client
+        .post("http://localhost:3000/bid/retract")
+        .json(&json!({"item_id": item.id, "bidder_id": 2}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["reason"], "retraction.closing_soon");
 }
 
-/// 경매 사이클 테스트
+/// 네덜란드식(가격 하락식) 경매 테스트
 #[tokio::test]
-async fn test_auction_lifecycle() {
+async fn test_dutch_auction() {
     let db_manager = setup().await;
     let client = Client::new();
+    let start_time = Utc::now();
+    let listing = json!({
+        "title": "가격 하락식 경매 테스트 아이템",
+        "description": "가격 하락식 경매 테스트를 위한 아이템입니다.",
+        "starting_price": 20000,
+        "buy_now_price": 20000,
+        "reserve_price": 15000,
+        "start_time": start_time,
+        "end_time": start_time + Duration::hours(1),
+        "auction_type": "DUTCH",
+        "price_drop_amount": 1000,
+        "price_drop_interval_secs": 1
+    });
+    let response = client
+        .post("http://localhost:3000/items")
+        .header("X-Seller", "TestSeller")
+        .json(&listing)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body: Value = response.json().await.unwrap();
+    let item_id = body["item_id"].as_i64().unwrap();
 
-    // 테스트용 아이템 생성 (시작 시간을 현재 시간으로 설정)
-    let item_id = {
-        let mut item = create_test_item(
-            &db_manager,
-            "경매 사이클 테스트 아이템".to_string(),
-            "경매 사이클 테스트(입찰 및 종료 대기, 종료 후 상태 확인)를 위한 아이템입니다."
-                .to_string(),
-        )
-        .await;
-        item.start_time = Utc::now();
-        item.end_time = Utc::now() + Duration::seconds(5);
-        let id = item.id;
-        update_test_item(&db_manager, item).await;
-        id
-    };
+    // 스케줄러가 일정에 따라 가격 하락 이벤트 기록
+    let item = wait_for_item(&db_manager, item_id, |item| {
+        item.status == "ACTIVE" && item.current_price <= 18000
+    })
+    .await;
+    assert_eq!(item.auction_type, "DUTCH");
+    let events = query::handlers::get_events_after_position(&db_manager, 0, Some(item_id), 100)
+        .await
+        .unwrap();
+    assert!(events
+        .iter()
+        .any(|event| event.event_type == "PriceDropped"));
 
-    // 경매 시작 전 상태 확인
-    let initial_item = query::handlers::get_item(&db_manager, item_id)
+    // 현재 가격 이상 입찰 시 현재 가격으로 낙찰
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&json!({"item_id": item_id, "bidder_id": 1, "bid_amount": 20000}))
+        .send()
         .await
         .unwrap();
-    assert_eq!(initial_item.status, "ACTIVE");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = response.json().await.unwrap();
+    let accepted_price = body["current_price"].as_i64().unwrap();
+    assert!((15000..=18000).contains(&accepted_price));
 
-    // 입찰 요청 생성
-    let bid_data = json!({
-        "item_id": item_id,
-        "bidder_id": 1,
-        "bid_amount": initial_item.current_price + 5000
-    });
+    let item = wait_for_item(&db_manager, item_id, |item| item.status == "COMPLETED").await;
+    assert_eq!(item.winner_id, Some(1));
+    assert_eq!(item.current_price, accepted_price);
 
-    // 입찰 처리
+    // 먼저 수락한 입찰자만 낙찰
     let response = client
         .post("http://localhost:3000/bid")
-        .json(&bid_data)
+        .json(&json!({"item_id": item_id, "bidder_id": 2, "bid_amount": 20000}))
         .send()
         .await
-        .expect("Failed to send request");
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["code"], "ALREADY_ENDED");
+}
 
-    assert!(response.status().is_success());
+/// 비공개 입찰(차가 낙찰) 경매 테스트
+#[tokio::test]
+async fn test_sealed_bid_auction() {
+    let db_manager = setup().await;
+    let client = Client::new();
+    let start_time = Utc::now();
+    let end_time = start_time + Duration::seconds(6);
+    let listing = json!({
+        "title": "비공개 입찰 경매 테스트 아이템",
+        "description": "비공개 입찰 경매 테스트를 위한 아이템입니다.",
+        "starting_price": 10000,
+        "buy_now_price": 500000,
+        "start_time": start_time,
+        "end_time": end_time,
+        "auction_type": "SEALED_SECOND_PRICE"
+    });
+    let response = client
+        .post("http://localhost:3000/items")
+        .header("X-Seller", "TestSeller")
+        .json(&listing)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body: Value = response.json().await.unwrap();
+    let item_id = body["item_id"].as_i64().unwrap();
+    wait_for_item(&db_manager, item_id, |item| item.status == "ACTIVE").await;
 
-    // 이벤트 처리 대기
-    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+    // 입찰자 1은 다시 제출하여 이전 입찰 대체
+    place_test_bid(&client, item_id, 1, 15000).await;
+    place_test_bid(&client, item_id, 2, 25000).await;
+    place_test_bid(&client, item_id, 1, 20000).await;
 
-    // 현재 상태 확인
-    let current_item = query::handlers::get_item(&db_manager, item_id)
+    // 종료 전에는 입찰 금액 및 현재 가격 비공개
+    let bids_url = format!("http://localhost:3000/auction/{}/bids", item_id);
+    let highest_bid_url = format!("http://localhost:3000/auction/{}/highest-bid", item_id);
+    let mut history: Vec<Value> = Vec::new();
+    for _ in 0..50 {
+        history = client
+            .get(&bids_url)
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        if history.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    }
+    assert_eq!(history.len(), 2);
+    assert!(history.iter().all(|bid| bid.get("bid_amount").is_none()));
+    let highest_bid: Value = client
+        .get(&highest_bid_url)
+        .send()
+        .await
+        .unwrap()
+        .json()
         .await
         .unwrap();
-    assert_eq!(
-        current_item.current_price,
-        initial_item.current_price + 5000
-    );
+    assert!(highest_bid.is_null());
+    let item = query::handlers::get_item(&db_manager, item_id)
+        .await
+        .unwrap();
+    assert_eq!(item.current_price, 10000);
 
-    // 경매 종료 대기
-    tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+    // 종료 시 최고 입찰자가 두 번째로 높은 입찰 금액으로 낙찰
+    let remaining = (end_time - Utc::now()).to_std().unwrap_or_default();
+    tokio::time::sleep(remaining).await;
+    let item = wait_for_item(&db_manager, item_id, |item| item.status == "COMPLETED").await;
+    assert_eq!(item.winner_id, Some(2));
+    assert_eq!(item.current_price, 20000);
 
-    // 경매 종료 후 상태 확인
-    let final_item = query::handlers::get_item(&db_manager, item_id)
+    // 종료 후 입찰 금액 공개
+    let history: Vec<Value> = client
+        .get(&bids_url)
+        .send()
+        .await
+        .unwrap()
+        .json()
         .await
         .unwrap();
-    assert_eq!(final_item.status, "COMPLETED");
+    assert!(history.iter().any(|bid| bid["bid_amount"] == 20000));
+    let highest_bid: Value = client
+        .get(&highest_bid_url)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(highest_bid, 25000);
 }
 
 /// 동시성 입찰 테스트
@@ -228,7 +1862,7 @@ async fn test_concurrent_bidding() {
 
             if status == StatusCode::OK {
                 successful_bids += 1;
-            } else if status == StatusCode::BAD_REQUEST {
+            } else if status.is_client_error() {
                 let error_info: Value = serde_json::from_str(&body).unwrap();
                 if error_info["code"] == "MAX_RETRIES_EXCEEDED" {
                     error!("최대 재시도 횟수 초과 오류 발생: {:?}", error_info);
@@ -276,6 +1910,200 @@ async fn test_concurrent_bidding() {
     }
 }
 
+/// 기존 스키마 마이그레이션 테스트
+/// 부팅 스크립트(`tests/fixtures/baseline-schema.sql`)로 생성된 데이터베이스에 마이그레이션을 적용하면 이후 추가된 컬럼과 테이블이 생성되고, 기존 상품과 입찰을 조회할 수 있음
+#[tokio::test]
+async fn test_migrations_on_baseline_schema() {
+    let db_manager = setup().await;
+    let schema = "baseline_migration_test";
+
+    // 부팅 스크립트로 별도 스키마에 기존 데이터베이스 생성
+    sqlx::raw_sql(&format!(
+        "DROP SCHEMA IF EXISTS {schema} CASCADE; CREATE SCHEMA {schema};"
+    ))
+    .execute(&*db_manager.pool)
+    .await
+    .unwrap();
+
+    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let options = database_url
+        .parse::<PgConnectOptions>()
+        .unwrap()
+        .options([("search_path", schema)]);
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .unwrap();
+    sqlx::raw_sql(include_str!("fixtures/baseline-schema.sql"))
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO bids (item_id, bid_time, bidder_id, bid_amount)
+         SELECT id, NOW(), 1, 60000 FROM items WHERE status = 'ACTIVE'",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // 마이그레이션 적용 (다시 적용해도 변경 없음)
+    let baseline = DatabaseManager {
+        pool: Arc::new(pool),
+    };
+    baseline.run_migrations().await.unwrap();
+    baseline.run_migrations().await.unwrap();
+
+    // 기존 상품과 입찰을 현재 모델로 조회
+    let items = query::handlers::get_all_items(&baseline).await.unwrap();
+    assert_eq!(items.len(), 3);
+    let active = items.iter().find(|item| item.status == "ACTIVE").unwrap();
+    assert_eq!(active.reserve_price, None);
+    assert_eq!(active.soft_close_window_secs, 0);
+    assert_eq!(active.winner_id, None);
+
+    let bids = query::handlers::get_bid_history(&baseline, active.id)
+        .await
+        .unwrap();
+    assert_eq!(bids.len(), 1);
+    assert!(!bids[0].is_auto);
+
+    // 이후 추가된 테이블 생성 확인
+    for table in [
+        "snapshots",
+        "outbox",
+        "projection_offsets",
+        "dead_letters",
+        "idempotency_keys",
+    ] {
+        let exists: bool = sqlx::query_scalar("SELECT to_regclass($1) IS NOT NULL")
+            .bind(format!("{schema}.{table}"))
+            .fetch_one(&*baseline.pool)
+            .await
+            .unwrap();
+        assert!(exists, "{} 테이블이 생성되지 않음", table);
+    }
+
+    baseline.pool.close().await;
+    sqlx::raw_sql(&format!("DROP SCHEMA {schema} CASCADE"))
+        .execute(&*db_manager.pool)
+        .await
+        .unwrap();
+}
+
+/// 테스트용 입찰 이벤트 (version 1)
+fn test_bid_event(item_id: i64, bid_amount: i64) -> Event {
+    let event = AuctionEvent::BidPlaced {
+        item_id,
+        bidder_id: 1,
+        bid_amount,
+        timestamp: Utc::now(),
+    };
+    Event {
+        id: 0,
+        aggregate_id: item_id,
+        event_type: event.event_type().to_string(),
+        data: serde_json::to_value(&event).unwrap(),
+        timestamp: Utc::now(),
+        version: 1,
+        idempotency_key: None,
+        position: 0,
+    }
+}
+
+/// 테스트 입찰 요청
+async fn place_test_bid(client: &Client, item_id: i64, bidder_id: i64, bid_amount: i64) {
+    let response = client
+        .post("http://localhost:3000/bid")
+        .json(&json!({
+            "item_id": item_id,
+            "bidder_id": bidder_id,
+            "bid_amount": bid_amount
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+}
+
+/// 조회 모델에 반영될 때까지 상품 조회 (5초 이내)
+async fn wait_for_item<F>(db_manager: &DatabaseManager, item_id: i64, condition: F) -> Item
+where
+    F: Fn(&Item) -> bool,
+{
+    for _ in 0..50 {
+        if let Ok(item) = query::handlers::get_item(db_manager, item_id).await {
+            if condition(&item) {
+                return item;
+            }
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    }
+    panic!("조회 모델 반영 시간 초과: item_id={}", item_id);
+}
+
+/// 실시간 구독 메시지 수신 (5초 이내)
+async fn next_message<S>(socket: &mut S) -> Value
+where
+    S: StreamExt<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+{
+    loop {
+        let message = tokio::time::timeout(tokio::time::Duration::from_secs(5), socket.next())
+            .await
+            .expect("실시간 메시지 수신 시간 초과")
+            .expect("연결 종료")
+            .unwrap();
+        if let Message::Text(text) = message {
+            return serde_json::from_str(&text).unwrap();
+        }
+    }
+}
+
+/// SSE 응답 읽기
+struct SseReader {
+    response: reqwest::Response,
+    buffer: String,
+}
+
+impl SseReader {
+    fn new(response: reqwest::Response) -> Self {
+        Self {
+            response,
+            buffer: String::new(),
+        }
+    }
+
+    /// 다음 SSE 이벤트 수신 (5초 이내, 데이터가 없는 keep-alive는 건너뜀)
+    async fn next_event(&mut self) -> (i64, String, Value) {
+        loop {
+            if let Some(end) = self.buffer.find("\n\n") {
+                let frame: String = self.buffer.drain(..end + 2).collect();
+                let (mut id, mut event, mut data) = (0, String::new(), String::new());
+                for line in frame.lines() {
+                    if let Some(value) = line.strip_prefix("id:") {
+                        id = value.trim().parse().unwrap();
+                    } else if let Some(value) = line.strip_prefix("event:") {
+                        event = value.trim().to_string();
+                    } else if let Some(value) = line.strip_prefix("data:") {
+                        data.push_str(value.trim());
+                    }
+                }
+                if !data.is_empty() {
+                    return (id, event, serde_json::from_str(&data).unwrap());
+                }
+                continue;
+            }
+            let chunk =
+                tokio::time::timeout(tokio::time::Duration::from_secs(5), self.response.chunk())
+                    .await
+                    .expect("SSE 이벤트 수신 시간 초과")
+                    .unwrap()
+                    .expect("연결 종료");
+            self.buffer.push_str(&String::from_utf8_lossy(&chunk));
+        }
+    }
+}
+
 // 여러 개의 테스트 아이템을 생성하는 함수
 async fn create_multiple_test_items(db_manager: &DatabaseManager, count: usize) -> Vec<Item> {
     let mut items = Vec::with_capacity(count);
@@ -318,22 +2146,72 @@ async fn create_test_item(
     })).await.unwrap()
 }
 
-/// 테스트용 아이템 업데이트
-async fn update_test_item(db_manager: &DatabaseManager, item: Item) {
+/// outbox에 메시지를 기록하여 메시지 브로커로 발행 (재전달 및 잘못된 메시지 발행 상황 재현)
+async fn publish_via_outbox(
+    db_manager: &DatabaseManager,
+    event_id: i64,
+    message_key: String,
+    payload: Value,
+) {
     db_manager
         .transaction(|tx| {
             Box::pin(async move {
                 sqlx::query(
-                    "UPDATE items SET start_time = $1, end_time = $2, status = $3 WHERE id = $4",
+                    "INSERT INTO outbox (event_id, topic, message_key, payload)
+                     VALUES ($1, 'events', $2, $3)",
+                )
+                .bind(event_id)
+                .bind(message_key)
+                .bind(payload)
+                .execute(&mut **tx)
+                .await
+            })
+        })
+        .await
+        .unwrap();
+}
+
+/// outbox 메시지 발행 완료 시각 조회
+async fn outbox_sent_at(db_manager: &DatabaseManager, id: i64) -> Option<DateTime<Utc>> {
+    db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query_scalar::<_, Option<DateTime<Utc>>>(
+                    "SELECT sent_at FROM outbox WHERE id = $1",
+                )
+                .bind(id)
+                .fetch_one(&mut **tx)
+                .await
+            })
+        })
+        .await
+        .unwrap()
+}
+
+/// 테스트용 아이템 업데이트
+async fn update_test_item(db_manager: &DatabaseManager, item: Item) -> Item {
+    db_manager
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query_as::<_, Item>(
+                    "UPDATE items SET start_time = $1, end_time = $2, status = $3,
+                     soft_close_window_secs = $4, soft_close_extension_secs = $5, reserve_price = $6,
+                     bid_increments = $7
+                     WHERE id = $8
+                     RETURNING *",
                 )
                 .bind(item.start_time)
                 .bind(item.end_time)
                 .bind(&item.status)
+                .bind(item.soft_close_window_secs)
+                .bind(item.soft_close_extension_secs)
+                .bind(item.reserve_price)
+                .bind(item.bid_increments)
                 .bind(item.id)
-                .execute(&mut **tx)
+                .fetch_one(&mut **tx)
                 .await
             })
         })
         .await
-        .unwrap();
+        .unwrap()
 }